@@ -1,6 +1,7 @@
 use std::env;
 use std::io::{self, Write};
 
+use modio::auth::EmailFlowState;
 use modio::{auth::Credentials, Modio};
 
 fn prompt(prompt: &str) -> io::Result<String> {
@@ -22,19 +23,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let email = prompt("Enter email: ")?;
 
     let modio = Modio::host(host, Credentials::new(api_key))?;
+    let mut flow = modio.auth().email_flow();
 
-    let terms = modio.auth().terms().await?;
-    println!("Terms:\n{}\n", terms.plaintext);
+    let mut state = flow.request_code(&email).await?;
+    let new_creds = loop {
+        state = match state {
+            EmailFlowState::TermsRequired(terms) => {
+                println!("Terms:\n{}\n", terms.plaintext);
 
-    match &*prompt("Accept? [Y/n]: ")? {
-        "" | "y" | "Y" => {}
-        _ => return Ok(()),
-    }
-
-    modio.auth().request_code(&email).await?;
-
-    let code = prompt("Enter security code: ").expect("read code");
-    let new_creds = modio.auth().security_code(&code).await?;
+                match &*prompt("Accept? [Y/n]: ")? {
+                    "" | "y" | "Y" => {}
+                    _ => return Ok(()),
+                }
+                flow.accept_terms();
+                flow.request_code(&email).await?
+            }
+            EmailFlowState::CodeSent => {
+                let code = prompt("Enter security code: ")?;
+                match flow.security_code(&code).await {
+                    Err(e) if e.is_validation() => {
+                        println!("{}", e);
+                        EmailFlowState::CodeSent
+                    }
+                    state => state?,
+                }
+            }
+            EmailFlowState::Authenticated(credentials) => break credentials,
+        };
+    };
     if let Some(token) = &new_creds.token {
         println!("Access token:\n{}", token.value);
     }
@@ -1,21 +1,12 @@
 use std::env;
 use std::process;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::Duration;
 
-use tokio::time::{self, Instant};
+use futures_util::StreamExt;
 
-use modio::filter::prelude::*;
+use modio::filter::Filter;
 use modio::{auth::Credentials, Modio};
 
-const TEN_SECS: Duration = Duration::from_secs(10);
-
-fn current_timestamp() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
-}
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
@@ -34,20 +25,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Creates a `Modio` endpoint for the test environment.
     let modio = Modio::host(host, creds)?;
 
-    // Creates an `Interval` that yields every 10 seconds starting in 10 seconds.
-    let mut interval = time::interval_at(Instant::now() + TEN_SECS, TEN_SECS);
-
-    loop {
-        let tstamp = current_timestamp();
-        interval.tick().await;
-
-        // Create an event filter for `date_added` > time.
-        let filter = DateAdded::gt(tstamp);
-        println!("event filter: {}", filter);
-
-        let list: Vec<_> = modio.user().events(filter).collect().await?;
-
-        println!("event count: {}", list.len());
-        println!("{:#?}", list);
+    // Creates a stream that polls the user events every 10 seconds.
+    let st = modio
+        .user()
+        .event_stream(Filter::default())
+        .interval(Duration::from_secs(10))
+        .into_stream();
+    tokio::pin!(st);
+
+    while let Some(event) = st.next().await {
+        match event {
+            Ok(event) => println!("{:#?}", event),
+            Err(e) => eprintln!("{}", e),
+        }
     }
+    Ok(())
 }
@@ -0,0 +1,165 @@
+use crate::error::{self, Error, Result};
+use crate::Modio;
+
+use super::{Credentials, Terms};
+
+const SECURITY_CODE_LEN: usize = 5;
+const INVALID_CODE: &str = "The security code must be 5 alphanumeric characters.";
+
+/// Guided email authentication flow created with [`Auth::email_flow`].
+///
+/// The flow requires the acceptance of the Terms of Use before the security code is requested.
+/// Until [`EmailFlow::accept_terms`] is called and whenever the API reports that the terms
+/// must be accepted, the steps return [`EmailFlowState::TermsRequired`] with the current terms.
+///
+/// [`Auth::email_flow`]: super::Auth::email_flow
+///
+/// # Example
+/// ```no_run
+/// use std::io::{self, Write};
+///
+/// use modio::auth::EmailFlowState;
+/// use modio::{Modio, Result};
+///
+/// fn prompt(prompt: &str) -> io::Result<String> {
+///     print!("{}", prompt);
+///     io::stdout().flush()?;
+///     let mut buffer = String::new();
+///     io::stdin().read_line(&mut buffer)?;
+///     Ok(buffer.trim().to_string())
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let modio = Modio::new("api-key")?;
+///     let mut flow = modio.auth().email_flow();
+///     let email = prompt("Enter email: ").expect("read email");
+///
+///     let mut state = flow.request_code(&email).await?;
+///     loop {
+///         state = match state {
+///             EmailFlowState::TermsRequired(terms) => {
+///                 println!("{}", terms.plaintext);
+///                 if prompt("Accept? [Y/n]: ").expect("read answer") == "n" {
+///                     return Ok(());
+///                 }
+///                 flow.accept_terms();
+///                 flow.request_code(&email).await?
+///             }
+///             EmailFlowState::CodeSent => {
+///                 let code = prompt("Enter security code: ").expect("read code");
+///                 match flow.security_code(&code).await {
+///                     Err(e) if e.is_validation() => EmailFlowState::CodeSent,
+///                     state => state?,
+///                 }
+///             }
+///             EmailFlowState::Authenticated(credentials) => {
+///                 let _modio = modio.with_credentials(credentials);
+///                 return Ok(());
+///             }
+///         };
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct EmailFlow {
+    modio: Modio,
+    terms_accepted: bool,
+}
+
+/// The state of an [`EmailFlow`] after a step.
+#[derive(Debug)]
+pub enum EmailFlowState {
+    /// The Terms of Use must be accepted with [`EmailFlow::accept_terms`] before the step is
+    /// repeated.
+    TermsRequired(Box<Terms>),
+    /// The security code was sent to the email address.
+    CodeSent,
+    /// The security code was exchanged for an access token.
+    ///
+    /// The credentials are saved to the [credential store](crate::Builder::credential_store)
+    /// of the client.
+    Authenticated(Credentials),
+}
+
+impl EmailFlow {
+    pub(crate) fn new(modio: Modio) -> Self {
+        Self {
+            modio,
+            terms_accepted: false,
+        }
+    }
+
+    /// Returns true if the Terms of Use were accepted.
+    pub fn terms_accepted(&self) -> bool {
+        self.terms_accepted
+    }
+
+    /// Mark the Terms of Use as accepted by the user.
+    pub fn accept_terms(&mut self) {
+        self.terms_accepted = true;
+    }
+
+    /// Request a security code be sent to the email of the user.
+    pub async fn request_code(&mut self, email: &str) -> Result<EmailFlowState> {
+        if !self.terms_accepted {
+            return self.terms_required().await;
+        }
+        match self.modio.auth().request_code(email).await {
+            Ok(()) => Ok(EmailFlowState::CodeSent),
+            Err(e) => self.map_error(e).await,
+        }
+    }
+
+    /// Exchange the security code for an access token.
+    ///
+    /// The code is checked to be 5 alphanumeric characters before it's sent. A malformed code
+    /// fails with a validation error.
+    pub async fn security_code(&mut self, code: &str) -> Result<EmailFlowState> {
+        let code = code.trim();
+        if !is_valid_code(code) {
+            let field = ("security_code".to_owned(), INVALID_CODE.to_owned());
+            return Err(error::validation(INVALID_CODE, vec![field]));
+        }
+        if !self.terms_accepted {
+            return self.terms_required().await;
+        }
+        match self.modio.auth().security_code(code).await {
+            Ok(credentials) => Ok(EmailFlowState::Authenticated(credentials)),
+            Err(e) => self.map_error(e).await,
+        }
+    }
+
+    async fn terms_required(&mut self) -> Result<EmailFlowState> {
+        self.terms_accepted = false;
+        let terms = self.modio.auth().terms().await?;
+        Ok(EmailFlowState::TermsRequired(Box::new(terms)))
+    }
+
+    async fn map_error(&mut self, err: Error) -> Result<EmailFlowState> {
+        if err.is_terms_acceptance_required() {
+            self.terms_required().await
+        } else {
+            Err(err)
+        }
+    }
+}
+
+fn is_valid_code(code: &str) -> bool {
+    code.len() == SECURITY_CODE_LEN && code.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_code;
+
+    #[test]
+    fn security_code_format() {
+        assert!(is_valid_code("QWERT"));
+        assert!(is_valid_code("C0001"));
+        assert!(!is_valid_code("QWER"));
+        assert!(!is_valid_code("QWERTY"));
+        assert!(!is_valid_code("QW RT"));
+        assert!(!is_valid_code("QWÉRT"));
+    }
+}
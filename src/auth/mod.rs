@@ -1,6 +1,13 @@
 //! Authentication Flow interface
 use std::collections::BTreeMap;
 use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
 
 use crate::routing::Route;
 use crate::types::auth::AccessToken;
@@ -8,22 +15,38 @@ use crate::types::Message;
 use crate::Modio;
 use crate::Result;
 
+mod email;
+pub mod store;
+
+use self::store::CredentialStore;
+
+pub use self::email::{EmailFlow, EmailFlowState};
 pub use crate::types::auth::{Link, Links, Terms};
 
 /// [mod.io](https://mod.io) credentials. API key with optional OAuth2 access token.
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct Credentials {
     pub api_key: String,
     pub token: Option<Token>,
 }
 
 /// Access token and optional Unix timestamp of the date this token will expire.
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct Token {
     pub value: String,
     pub expired_at: Option<u64>,
 }
 
+impl Token {
+    /// Returns `true` if the token has an expiry date which has passed.
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        matches!(self.expired_at, Some(expired_at) if expired_at <= now)
+    }
+}
+
 impl fmt::Debug for Credentials {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.token.is_some() {
@@ -77,6 +100,115 @@ impl From<(String, String)> for Credentials {
     }
 }
 
+pub(crate) type ReauthenticateFn =
+    Arc<dyn Fn(Modio) -> BoxFuture<'static, Result<Credentials>> + Send + Sync>;
+
+/// Credentials of a client shared by all its clones, the callback set with
+/// [`Builder::on_token_expired`](crate::Builder::on_token_expired) and the store of new
+/// credentials.
+pub(crate) struct Session {
+    credentials: RwLock<Credentials>,
+    reauthenticate: Option<ReauthenticateFn>,
+    store: Option<Arc<dyn CredentialStore>>,
+    refresh: tokio::sync::Mutex<()>,
+}
+
+impl Session {
+    pub(crate) fn new(
+        credentials: Credentials,
+        reauthenticate: Option<ReauthenticateFn>,
+        store: Option<Arc<dyn CredentialStore>>,
+    ) -> Self {
+        Self {
+            credentials: RwLock::new(credentials),
+            reauthenticate,
+            store,
+            refresh: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub(crate) fn reauthenticate_fn<F, Fut>(f: F) -> ReauthenticateFn
+    where
+        F: Fn(Modio) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Credentials>> + Send + 'static,
+    {
+        Arc::new(move |modio| Box::pin(f(modio)))
+    }
+
+    /// Returns a new session with other credentials and the same callback and store.
+    pub(crate) fn with_credentials(&self, credentials: Credentials) -> Self {
+        Self::new(credentials, self.reauthenticate.clone(), self.store.clone())
+    }
+
+    pub(crate) fn credentials(&self) -> Credentials {
+        self.credentials
+            .read()
+            .expect("credentials poisoned")
+            .clone()
+    }
+
+    pub(crate) fn can_reauthenticate(&self) -> bool {
+        self.reauthenticate.is_some()
+    }
+
+    /// Replaces the `stale` token with the credentials returned by the callback.
+    ///
+    /// Concurrent requests with the same stale token wait for the first refresh and use its
+    /// credentials instead of invoking the callback again.
+    pub(crate) async fn reauthenticate(
+        &self,
+        modio: &Modio,
+        stale: Option<&Token>,
+    ) -> Result<Credentials> {
+        let _guard = self.refresh.lock().await;
+
+        let current = self.credentials();
+        let refreshed = current.token.as_ref() != stale;
+        if refreshed && !current.token.as_ref().map_or(false, Token::is_expired) {
+            return Ok(current);
+        }
+        let reauthenticate = match &self.reauthenticate {
+            Some(f) => f,
+            None => return Ok(current),
+        };
+
+        debug!("access token expired, reauthenticating");
+        let modio = modio.with_credentials(Credentials::new(current.api_key));
+        let credentials = reauthenticate(modio).await?;
+        *self.credentials.write().expect("credentials poisoned") = credentials.clone();
+        self.save(&credentials).await;
+        Ok(credentials)
+    }
+
+    /// Saves new credentials to the credential store. Failures are logged.
+    pub(crate) async fn save(&self, credentials: &Credentials) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.save(credentials).await {
+                warn!("failed to save credentials: {e}");
+            }
+        }
+    }
+
+    /// Removes the credentials from the credential store. Failures are logged.
+    async fn clear(&self) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.clear().await {
+                warn!("failed to remove credentials: {e}");
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("credentials", &self.credentials())
+            .field("reauthenticate", &self.reauthenticate.is_some())
+            .field("store", &self.store.is_some())
+            .finish()
+    }
+}
+
 /// Authentication Flow interface to retrieve access tokens. See the [mod.io Authentication
 /// docs](https://docs.mod.io/#email-exchange) for more information.
 ///
@@ -127,6 +259,12 @@ impl Auth {
         self.modio.request(Route::Terms).send().await
     }
 
+    /// Start a guided [`EmailFlow`] that handles the Terms of Use, requests the security code
+    /// and exchanges it for an access token.
+    pub fn email_flow(self) -> EmailFlow {
+        EmailFlow::new(self.modio)
+    }
+
     /// Request a security code be sent to the email of the user. [required: apikey]
     pub async fn request_code(self, email: &str) -> Result<()> {
         self.modio
@@ -139,6 +277,8 @@ impl Auth {
     }
 
     /// Get the access token for a security code. [required: apikey]
+    ///
+    /// The credentials are saved to the [credential store](crate::Builder::credential_store) of the client.
     pub async fn security_code(self, code: &str) -> Result<Credentials> {
         let t = self
             .modio
@@ -151,22 +291,32 @@ impl Auth {
             value: t.value,
             expired_at: t.expired_at,
         };
-        Ok(Credentials {
-            api_key: self.modio.inner.credentials.api_key.clone(),
+        let credentials = Credentials {
+            api_key: self.modio.inner.session.credentials().api_key,
             token: Some(token),
-        })
+        };
+        self.modio.inner.session.save(&credentials).await;
+        Ok(credentials)
     }
 
-    /// Authenticate via external services ([Steam], [Switch], [Xbox], [Discord], [Oculus], [Google]).
+    /// Authenticate via external services ([Steam], [GOG Galaxy], [itch.io], [Switch], [Xbox],
+    /// [PSN], [Epic Games], [Discord], [Oculus], [Google], [OpenID]).
+    ///
+    /// The credentials are saved to the [credential store](crate::Builder::credential_store) of the client.
     ///
     /// See the [mod.io docs](https://docs.mod.io/#authentication-2) for more information.
     ///
     /// [Steam]: SteamOptions
+    /// [GOG Galaxy]: GalaxyOptions
+    /// [itch.io]: ItchioOptions
     /// [Oculus]: OculusOptions
     /// [Switch]: SwitchOptions
     /// [Xbox]: XboxOptions
+    /// [PSN]: PsnOptions
+    /// [Epic Games]: EpicOptions
     /// [Discord]: DiscordOptions
     /// [Google]: GoogleOptions
+    /// [OpenID]: OpenIdOptions
     ///
     /// # Examples
     ///
@@ -198,19 +348,24 @@ impl Auth {
             value: t.value,
             expired_at: t.expired_at,
         };
-        Ok(Credentials {
-            api_key: self.modio.inner.credentials.api_key.clone(),
+        let credentials = Credentials {
+            api_key: self.modio.inner.session.credentials().api_key,
             token: Some(token),
-        })
+        };
+        self.modio.inner.session.save(&credentials).await;
+        Ok(credentials)
     }
 
     /// Logout by revoking the current access token.
+    ///
+    /// The credentials are removed from the [credential store](crate::Builder::credential_store) of the client.
     pub async fn logout(self) -> Result<()> {
         self.modio
             .request(Route::OAuthLogout)
             .send::<Message>()
             .await?;
 
+        self.modio.inner.session.clear().await;
         Ok(())
     }
 }
@@ -222,6 +377,24 @@ pub struct AuthOptions {
 }
 
 // impl From<*Options> for AuthOptions {{{
+impl From<GalaxyOptions> for AuthOptions {
+    fn from(options: GalaxyOptions) -> AuthOptions {
+        AuthOptions {
+            route: Route::ExternalAuthGalaxy,
+            params: options.params,
+        }
+    }
+}
+
+impl From<ItchioOptions> for AuthOptions {
+    fn from(options: ItchioOptions) -> AuthOptions {
+        AuthOptions {
+            route: Route::ExternalAuthItchio,
+            params: options.params,
+        }
+    }
+}
+
 impl From<OculusOptions> for AuthOptions {
     fn from(options: OculusOptions) -> AuthOptions {
         AuthOptions {
@@ -275,6 +448,33 @@ impl From<GoogleOptions> for AuthOptions {
         }
     }
 }
+
+impl From<EpicOptions> for AuthOptions {
+    fn from(options: EpicOptions) -> AuthOptions {
+        AuthOptions {
+            route: Route::ExternalAuthEpic,
+            params: options.params,
+        }
+    }
+}
+
+impl From<PsnOptions> for AuthOptions {
+    fn from(options: PsnOptions) -> AuthOptions {
+        AuthOptions {
+            route: Route::ExternalAuthPSN,
+            params: options.params,
+        }
+    }
+}
+
+impl From<OpenIdOptions> for AuthOptions {
+    fn from(options: OpenIdOptions) -> AuthOptions {
+        AuthOptions {
+            route: Route::ExternalAuthOpenID,
+            params: options.params,
+        }
+    }
+}
 // }}}
 
 /// Authentication options for an encrypted gog app ticket.
@@ -499,4 +699,88 @@ impl GoogleOptions {
     option!(terms_agreed bool >> "terms_agreed");
 }
 
+/// Authentication options for an Epic Games id token.
+///
+/// See the [mod.io docs](https://docs.mod.io/#epic-games) for more information.
+pub struct EpicOptions {
+    params: BTreeMap<&'static str, String>,
+}
+
+impl EpicOptions {
+    pub fn new<T>(id_token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut params = BTreeMap::new();
+        params.insert("id_token", id_token.into());
+        Self { params }
+    }
+
+    option!(email >> "email");
+    option!(
+        /// Unix timestamp of date in which the returned token will expire. Value cannot be higher
+        /// than the default value which is a common year.
+        expired_at u64 >> "date_expires"
+    );
+    option!(terms_agreed bool >> "terms_agreed");
+}
+
+/// Authentication options for a PlayStation Network auth code.
+///
+/// See the [mod.io docs](https://docs.mod.io/#playstation-network) for more information.
+pub struct PsnOptions {
+    params: BTreeMap<&'static str, String>,
+}
+
+impl PsnOptions {
+    pub fn new<T>(auth_code: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut params = BTreeMap::new();
+        params.insert("auth_code", auth_code.into());
+        Self { params }
+    }
+
+    option!(
+        /// The PSN environment the auth code was issued for. Defaults to the production
+        /// environment (`256`).
+        env u32 >> "env"
+    );
+    option!(email >> "email");
+    option!(
+        /// Unix timestamp of date in which the returned token will expire. Value cannot be higher
+        /// than the default value which is a common year.
+        expired_at u64 >> "date_expires"
+    );
+    option!(terms_agreed bool >> "terms_agreed");
+}
+
+/// Authentication options for an OpenID id token.
+///
+/// The OpenID provider must be configured for the game on mod.io.
+///
+/// See the [mod.io docs](https://docs.mod.io/#openid) for more information.
+pub struct OpenIdOptions {
+    params: BTreeMap<&'static str, String>,
+}
+
+impl OpenIdOptions {
+    pub fn new<T>(id_token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut params = BTreeMap::new();
+        params.insert("id_token", id_token.into());
+        Self { params }
+    }
+
+    option!(
+        /// Unix timestamp of date in which the returned token will expire. Value cannot be higher
+        /// than the default value which is a common year.
+        expired_at u64 >> "date_expires"
+    );
+    option!(terms_agreed bool >> "terms_agreed");
+}
+
 // vim: fdm=marker
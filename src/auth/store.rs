@@ -0,0 +1,258 @@
+//! Persistence of credentials.
+//!
+//! A [`CredentialStore`] set with [`Builder::credential_store`] or
+//! [`Builder::load_credentials`] saves the credentials returned by
+//! [`Auth::security_code`], [`Auth::external`] and the callback of
+//! [`Builder::on_token_expired`]. [`Auth::logout`] removes the saved credentials.
+//!
+//! [`Builder::credential_store`]: crate::Builder::credential_store
+//! [`Builder::load_credentials`]: crate::Builder::load_credentials
+//! [`Builder::on_token_expired`]: crate::Builder::on_token_expired
+//! [`Auth::security_code`]: super::Auth::security_code
+//! [`Auth::external`]: super::Auth::external
+//! [`Auth::logout`]: super::Auth::logout
+//!
+//! # Example
+//! ```no_run
+//! use modio::auth::store::FileStore;
+//! use modio::Modio;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> modio::Result<()> {
+//! let modio = Modio::builder("api-key")
+//!     .load_credentials(FileStore::new("credentials.json"))
+//!     .await
+//!     .build()?;
+//!
+//! if modio.credentials().token.is_none() {
+//!     modio.auth().request_code("john@example.com").await?;
+//!     // The credentials are saved to `credentials.json`.
+//!     let credentials = modio.auth().security_code("QWERT").await?;
+//!     let _modio = modio.with_credentials(credentials);
+//! }
+//! #     Ok(())
+//! # }
+//! ```
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use futures_util::future::BoxFuture;
+
+use super::Credentials;
+use crate::error::{self, Result};
+use crate::fs;
+
+/// Storage of the credentials of a client.
+pub trait CredentialStore: Send + Sync {
+    /// Load the saved credentials.
+    fn load(&self) -> BoxFuture<'_, Result<Option<Credentials>>>;
+
+    /// Save the credentials.
+    fn save<'a>(&'a self, credentials: &'a Credentials) -> BoxFuture<'a, Result<()>>;
+
+    /// Remove the saved credentials.
+    fn clear(&self) -> BoxFuture<'_, Result<()>>;
+}
+
+impl<S: CredentialStore + ?Sized> CredentialStore for Arc<S> {
+    fn load(&self) -> BoxFuture<'_, Result<Option<Credentials>>> {
+        (**self).load()
+    }
+
+    fn save<'a>(&'a self, credentials: &'a Credentials) -> BoxFuture<'a, Result<()>> {
+        (**self).save(credentials)
+    }
+
+    fn clear(&self) -> BoxFuture<'_, Result<()>> {
+        (**self).clear()
+    }
+}
+
+/// Credential store that keeps the credentials in a JSON file.
+///
+/// On Unix the file is only readable and writable by its owner. With the `encryption` feature
+/// the file can be encrypted with a caller-supplied key, see [`FileStore::encrypted`].
+pub struct FileStore {
+    path: PathBuf,
+    #[cfg(feature = "encryption")]
+    key: Option<encryption::Key>,
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl FileStore {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            #[cfg(feature = "encryption")]
+            key: None,
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Encrypt the file with AES-256-GCM and the 256-bit key.
+    ///
+    /// Loading fails with a decode error if the file was saved with another key or without
+    /// encryption.
+    #[cfg(feature = "encryption")]
+    #[must_use]
+    pub fn encrypted(self, key: [u8; 32]) -> Self {
+        Self {
+            key: Some(key.into()),
+            ..self
+        }
+    }
+
+    fn encode(&self, credentials: &Credentials) -> Result<Vec<u8>> {
+        let data = serde_json::to_vec_pretty(credentials).map_err(error::decode)?;
+        #[cfg(feature = "encryption")]
+        if let Some(key) = &self.key {
+            return encryption::encrypt(key, &data);
+        }
+        Ok(data)
+    }
+
+    fn decode(&self, data: &[u8]) -> Result<Credentials> {
+        #[cfg(feature = "encryption")]
+        if let Some(key) = &self.key {
+            let data = encryption::decrypt(key, data)?;
+            return serde_json::from_slice(&data).map_err(error::decode);
+        }
+        serde_json::from_slice(data).map_err(error::decode)
+    }
+}
+
+impl fmt::Debug for FileStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("FileStore");
+        s.field("path", &self.path);
+        #[cfg(feature = "encryption")]
+        s.field("encrypted", &self.key.is_some());
+        s.finish()
+    }
+}
+
+impl CredentialStore for FileStore {
+    fn load(&self) -> BoxFuture<'_, Result<Option<Credentials>>> {
+        Box::pin(async move {
+            let _guard = self.lock.lock().await;
+            match fs::read(&self.path).await? {
+                Some(data) => self.decode(&data).map(Some),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn save<'a>(&'a self, credentials: &'a Credentials) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let data = self.encode(credentials)?;
+            let _guard = self.lock.lock().await;
+            fs::write_atomic(&self.path, &data, true).await
+        })
+    }
+
+    fn clear(&self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async move {
+            let _guard = self.lock.lock().await;
+            match tokio::fs::remove_file(&self.path).await {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(error::io(e)),
+                _ => Ok(()),
+            }
+        })
+    }
+}
+
+#[cfg(feature = "encryption")]
+mod encryption {
+    use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+    use aes_gcm::{Aes256Gcm, Nonce};
+
+    use crate::error::{self, Result};
+
+    pub type Key = aes_gcm::Key<Aes256Gcm>;
+
+    const NONCE_LEN: usize = 12;
+
+    /// Returns the random nonce followed by the ciphertext.
+    pub fn encrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = Aes256Gcm::new(key)
+            .encrypt(&nonce, data)
+            .map_err(|_| error::decode("failed to encrypt credentials"))?;
+
+        let mut out = nonce.to_vec();
+        out.extend(ciphertext);
+        Ok(out)
+    }
+
+    pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>> {
+        if data.len() < NONCE_LEN {
+            return Err(error::decode("invalid encrypted credentials"));
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        Aes256Gcm::new(key)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| error::decode("failed to decrypt credentials"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CredentialStore, FileStore};
+    use crate::auth::Credentials;
+
+    #[tokio::test]
+    async fn file_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("credentials.json");
+        let store = FileStore::new(&path);
+        assert!(store.load().await.unwrap().is_none());
+
+        let credentials = Credentials::with_token("foo", "bar");
+        store.save(&credentials).await.unwrap();
+        assert!(store.load().await.unwrap() == Some(credentials));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        store.clear().await.unwrap();
+        assert!(!path.exists());
+        store.clear().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn file_store_io_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileStore::new(dir.path());
+
+        let err = store.load().await.unwrap_err();
+        assert!(err.is_io() && !err.is_decode());
+        assert!(store.clear().await.unwrap_err().is_io());
+    }
+
+    #[cfg(feature = "encryption")]
+    #[tokio::test]
+    async fn encrypted_file_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("credentials.json");
+        let store = FileStore::new(&path).encrypted([7; 32]);
+
+        let credentials = Credentials::with_token("foo", "secret-token");
+        store.save(&credentials).await.unwrap();
+        let data = std::fs::read(&path).unwrap();
+        assert!(!String::from_utf8_lossy(&data).contains("secret-token"));
+        assert!(store.load().await.unwrap() == Some(credentials));
+
+        let err = FileStore::new(&path).encrypted([8; 32]).load().await;
+        assert!(err.unwrap_err().is_decode());
+        let err = FileStore::new(&path).load().await;
+        assert!(err.unwrap_err().is_decode());
+
+        store.clear().await.unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+use super::Modio;
+use crate::auth::{AuthOptions, Credentials, EmailFlowState, Terms};
+use crate::Result;
+
+/// Blocking interface for requesting access tokens.
+#[derive(Clone)]
+pub struct Auth {
+    modio: Modio,
+    inner: crate::auth::Auth,
+}
+
+impl Auth {
+    pub(crate) fn new(modio: Modio, inner: crate::auth::Auth) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Get text and links for user agreement and consent prior to authentication. [required: apikey]
+    pub fn terms(self) -> Result<Terms> {
+        self.modio.block_on(self.inner.terms())
+    }
+
+    /// Start a guided [`EmailFlow`] that handles the Terms of Use, requests the security code
+    /// and exchanges it for an access token.
+    pub fn email_flow(self) -> EmailFlow {
+        EmailFlow::new(self.modio, self.inner.email_flow())
+    }
+
+    /// Request a security code be sent to the email of the user. [required: apikey]
+    pub fn request_code(self, email: &str) -> Result<()> {
+        self.modio.block_on(self.inner.request_code(email))
+    }
+
+    /// Get the access token for a security code. [required: apikey]
+    pub fn security_code(self, code: &str) -> Result<Credentials> {
+        self.modio.block_on(self.inner.security_code(code))
+    }
+
+    /// Authenticate via external services.
+    ///
+    /// See [`Auth::external`](crate::auth::Auth::external).
+    pub fn external<T>(self, auth_options: T) -> Result<Credentials>
+    where
+        T: Into<AuthOptions>,
+    {
+        self.modio.block_on(self.inner.external(auth_options))
+    }
+
+    /// Logout by revoking the current access token.
+    pub fn logout(self) -> Result<()> {
+        self.modio.block_on(self.inner.logout())
+    }
+}
+
+/// Blocking guided email authentication flow created with [`Auth::email_flow`].
+///
+/// See [`EmailFlow`](crate::auth::EmailFlow).
+#[derive(Debug)]
+pub struct EmailFlow {
+    modio: Modio,
+    inner: crate::auth::EmailFlow,
+}
+
+impl EmailFlow {
+    fn new(modio: Modio, inner: crate::auth::EmailFlow) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Returns true if the Terms of Use were accepted.
+    pub fn terms_accepted(&self) -> bool {
+        self.inner.terms_accepted()
+    }
+
+    /// Mark the Terms of Use as accepted by the user.
+    pub fn accept_terms(&mut self) {
+        self.inner.accept_terms();
+    }
+
+    /// Request a security code be sent to the email of the user.
+    pub fn request_code(&mut self, email: &str) -> Result<EmailFlowState> {
+        self.modio.block_on(self.inner.request_code(email))
+    }
+
+    /// Exchange the security code for an access token.
+    pub fn security_code(&mut self, code: &str) -> Result<EmailFlowState> {
+        self.modio.block_on(self.inner.security_code(code))
+    }
+}
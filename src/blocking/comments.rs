@@ -0,0 +1,57 @@
+use super::{Modio, Query};
+use crate::comments::{Comment, Karma};
+use crate::filter::Filter;
+use crate::types::id::CommentId;
+use crate::{Editing, Result};
+
+/// Blocking interface for comments of a mod.
+#[derive(Clone)]
+pub struct Comments {
+    modio: Modio,
+    inner: crate::comments::Comments,
+}
+
+impl Comments {
+    pub(crate) fn new(modio: Modio, inner: crate::comments::Comments) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Returns a `Query` interface to retrieve all comments.
+    ///
+    /// See [Filters and sorting](crate::comments::filters).
+    pub fn search(&self, filter: Filter) -> Query<Comment> {
+        Query::new(self.modio.clone(), self.inner.search(filter))
+    }
+
+    /// Return comment by id.
+    pub fn get(self, id: CommentId) -> Result<Comment> {
+        self.modio.block_on(self.inner.get(id))
+    }
+
+    /// Add a new comment. [required: token]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<S>(self, content: S, reply_id: Option<CommentId>) -> Result<Comment>
+    where
+        S: Into<String>,
+    {
+        self.modio.block_on(self.inner.add(content, reply_id))
+    }
+
+    /// Edit a comment by id. [required: token]
+    pub fn edit<S>(self, id: CommentId, content: S) -> Result<Comment>
+    where
+        S: Into<String>,
+    {
+        self.modio.block_on(self.inner.edit(id, content))
+    }
+
+    /// Delete a comment by id. [required: token]
+    pub fn delete(self, id: CommentId) -> Result<()> {
+        self.modio.block_on(self.inner.delete(id))
+    }
+
+    /// Update the karma for a comment. [required: token]
+    pub fn karma(self, id: CommentId, karma: Karma) -> Result<Editing<Comment>> {
+        self.modio.block_on(self.inner.karma(id, karma))
+    }
+}
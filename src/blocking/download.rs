@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use bytes::Bytes;
+
+use super::Modio;
+use crate::download::Progress;
+use crate::types::files::File;
+use crate::Result;
+
+/// Blocking `Downloader` to save a mod file to a local file or retrieve the data.
+/// Constructed with [`Modio::download`].
+///
+/// See [`Downloader`](crate::download::Downloader).
+pub struct Downloader {
+    modio: Modio,
+    inner: crate::download::Downloader,
+}
+
+impl Downloader {
+    pub(crate) fn new(modio: Modio, inner: crate::download::Downloader) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Verify the size and the MD5 hash of the downloaded data against the metadata of the
+    /// mod file.
+    #[must_use]
+    pub fn verify(self) -> Self {
+        Self {
+            inner: self.inner.verify(),
+            ..self
+        }
+    }
+
+    /// Register a callback that is called with the [`Progress`] of the download after each
+    /// received chunk of data.
+    #[must_use]
+    pub fn on_progress<F>(self, callback: F) -> Self
+    where
+        F: FnMut(&Progress) + Send + 'static,
+    {
+        Self {
+            inner: self.inner.on_progress(callback),
+            ..self
+        }
+    }
+
+    /// Returns the metadata of the mod file that is downloaded.
+    pub fn file(&self) -> &File {
+        self.inner.file()
+    }
+
+    /// Save the mod file to a local file.
+    pub fn save_to_file<P: AsRef<Path>>(self, file: P) -> Result<()> {
+        self.modio.block_on(self.inner.save_to_file(file))
+    }
+
+    /// Save the mod file to a local file and resume a previously interrupted download.
+    pub fn resume_to_file<P: AsRef<Path>>(self, file: P) -> Result<()> {
+        self.modio.block_on(self.inner.resume_to_file(file))
+    }
+
+    /// Get the full mod file as `Bytes`.
+    pub fn bytes(self) -> Result<Bytes> {
+        self.modio.block_on(self.inner.bytes())
+    }
+
+    /// Get the size of the mod file from its metadata.
+    pub fn content_length(&self) -> Option<u64> {
+        self.inner.content_length()
+    }
+}
@@ -0,0 +1,72 @@
+use super::{Modio, Query};
+use crate::files::{AddFileOptions, EditFileOptions, EditPlatformStatusOptions};
+use crate::filter::Filter;
+use crate::types::files::File;
+use crate::types::id::FileId;
+use crate::{Editing, Result};
+
+/// Blocking interface for the modfiles of a mod.
+#[derive(Clone)]
+pub struct Files {
+    modio: Modio,
+    inner: crate::files::Files,
+}
+
+impl Files {
+    pub(crate) fn new(modio: Modio, inner: crate::files::Files) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Returns a `Query` interface to retrieve all files that are published
+    /// for a mod this `Files` refers to.
+    ///
+    /// See [Filters and sorting](crate::files::filters).
+    pub fn search(&self, filter: Filter) -> Query<File> {
+        Query::new(self.modio.clone(), self.inner.search(filter))
+    }
+
+    /// Return a reference to a file.
+    pub fn get(&self, id: FileId) -> FileRef {
+        FileRef::new(self.modio.clone(), self.inner.get(id))
+    }
+
+    /// Add a file for a mod that this `Files` refers to. [required: token]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, options: AddFileOptions) -> Result<File> {
+        self.modio.block_on(self.inner.add(options))
+    }
+}
+
+/// Blocking reference interface of a modfile.
+#[derive(Clone)]
+pub struct FileRef {
+    modio: Modio,
+    inner: crate::files::FileRef,
+}
+
+impl FileRef {
+    pub(crate) fn new(modio: Modio, inner: crate::files::FileRef) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Get a reference to the Modio modfile object that this `FileRef` refers to.
+    pub fn get(self) -> Result<File> {
+        self.modio.block_on(self.inner.get())
+    }
+
+    /// Edit details of a modfile. [required: token]
+    pub fn edit(self, options: EditFileOptions) -> Result<Editing<File>> {
+        self.modio.block_on(self.inner.edit(options))
+    }
+
+    /// Delete a modfile. [required: token]
+    pub fn delete(self) -> Result<()> {
+        self.modio.block_on(self.inner.delete())
+    }
+
+    /// Edit the platform status of a modfile. [required: token]
+    pub fn edit_platform_status(self, options: EditPlatformStatusOptions) -> Result<File> {
+        self.modio
+            .block_on(self.inner.edit_platform_status(options))
+    }
+}
@@ -0,0 +1,116 @@
+use super::loader::Iter;
+use super::{ModRef, Modio, Mods, Query};
+use crate::filter::Filter;
+use crate::games::{AddTagsOptions, DeleteTagsOptions, EditMediaOptions};
+use crate::types::games::{Game, Statistics, TagOption};
+use crate::types::id::{GameId, ModId};
+use crate::{Deletion, Result};
+
+/// Blocking interface for games.
+#[derive(Clone)]
+pub struct Games {
+    modio: Modio,
+    inner: crate::games::Games,
+}
+
+impl Games {
+    pub(crate) fn new(modio: Modio, inner: crate::games::Games) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Returns a `Query` interface to retrieve games.
+    ///
+    /// See [Filters and sorting](crate::games::filters).
+    pub fn search(&self, filter: Filter) -> Query<Game> {
+        Query::new(self.modio.clone(), self.inner.search(filter))
+    }
+
+    /// Return a reference to a game.
+    pub fn get(&self, id: GameId) -> GameRef {
+        GameRef::new(self.modio.clone(), self.inner.get(id))
+    }
+}
+
+/// Blocking reference interface of a game.
+#[derive(Clone)]
+pub struct GameRef {
+    modio: Modio,
+    inner: crate::games::GameRef,
+}
+
+impl GameRef {
+    pub(crate) fn new(modio: Modio, inner: crate::games::GameRef) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Get a reference to the Modio game object that this `GameRef` refers to.
+    pub fn get(self) -> Result<Game> {
+        self.modio.block_on(self.inner.get())
+    }
+
+    /// Return a reference to a mod of a game.
+    pub fn mod_(&self, mod_id: ModId) -> ModRef {
+        ModRef::new(self.modio.clone(), self.inner.mod_(mod_id))
+    }
+
+    /// Return a reference to an interface that provides access to the mods of a game.
+    pub fn mods(&self) -> Mods {
+        Mods::new(self.modio.clone(), self.inner.mods())
+    }
+
+    /// Return the statistics for a game.
+    pub fn statistics(self) -> Result<Statistics> {
+        self.modio.block_on(self.inner.statistics())
+    }
+
+    /// Return a reference to an interface that provides access to the tags of a game.
+    pub fn tags(&self) -> Tags {
+        Tags::new(self.modio.clone(), self.inner.tags())
+    }
+
+    /// Add new media to a game. [required: token]
+    pub fn edit_media(self, media: EditMediaOptions) -> Result<()> {
+        self.modio.block_on(self.inner.edit_media(media))
+    }
+}
+
+/// Blocking interface for the tag options of a game.
+#[derive(Clone)]
+pub struct Tags {
+    modio: Modio,
+    inner: crate::games::Tags,
+}
+
+impl Tags {
+    fn new(modio: Modio, inner: crate::games::Tags) -> Self {
+        Self { modio, inner }
+    }
+
+    /// List tag options.
+    pub fn list(self) -> Result<Vec<TagOption>> {
+        self.modio.block_on(self.inner.list())
+    }
+
+    /// Provides an iterator over all tag options.
+    #[allow(clippy::iter_not_returning_iterator)]
+    pub fn iter(self) -> Result<impl Iterator<Item = Result<TagOption>>> {
+        let stream = self.modio.block_on(self.inner.iter())?;
+        Ok(Iter::new(self.modio, Box::pin(stream)))
+    }
+
+    /// Add tag options. [required: token]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, options: AddTagsOptions) -> Result<()> {
+        self.modio.block_on(self.inner.add(options))
+    }
+
+    /// Delete tag options. [required: token]
+    pub fn delete(self, options: DeleteTagsOptions) -> Result<Deletion> {
+        self.modio.block_on(self.inner.delete(options))
+    }
+
+    /// Rename an existing tag, updating all mods in the progress. [required: token]
+    pub fn rename(self, from: String, to: String) -> Result<()> {
+        self.modio.block_on(self.inner.rename(from, to))
+    }
+}
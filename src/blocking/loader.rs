@@ -0,0 +1,74 @@
+use futures_util::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+
+use super::Modio;
+use crate::loader::Page;
+use crate::Result;
+
+/// Blocking interface for retrieving search results.
+pub struct Query<T> {
+    modio: Modio,
+    inner: crate::Query<T>,
+}
+
+impl<T> Query<T> {
+    pub(crate) fn new(modio: Modio, inner: crate::Query<T>) -> Self {
+        Self { modio, inner }
+    }
+}
+
+impl<T: DeserializeOwned + Send> Query<T> {
+    /// Returns the first search result.
+    pub fn first(self) -> Result<Option<T>> {
+        self.modio.block_on(self.inner.first())
+    }
+
+    /// Returns the first search result page.
+    pub fn first_page(self) -> Result<Vec<T>> {
+        self.modio.block_on(self.inner.first_page())
+    }
+
+    /// Returns the complete search result list.
+    pub fn collect(self) -> Result<Vec<T>> {
+        self.modio.block_on(self.inner.collect())
+    }
+
+    /// Provides an iterator over all search result items.
+    ///
+    /// The pages are requested while iterating.
+    #[allow(clippy::iter_not_returning_iterator)]
+    pub fn iter(self) -> Result<impl Iterator<Item = Result<T>>> {
+        let stream = self.modio.block_on(self.inner.iter())?;
+        Ok(Iter::new(self.modio, stream))
+    }
+
+    /// Provides an iterator over all search result pages.
+    pub fn paged(self) -> Result<impl Iterator<Item = Result<Page<T>>>> {
+        let stream = self.modio.block_on(self.inner.paged())?;
+        Ok(Iter::new(self.modio, Box::pin(stream)))
+    }
+}
+
+/// Iterator over the items of a stream.
+pub(super) struct Iter<St> {
+    modio: Modio,
+    stream: St,
+}
+
+impl<St> Iter<St> {
+    pub(super) fn new(modio: Modio, stream: St) -> Self {
+        Self { modio, stream }
+    }
+}
+
+impl<St: Stream + Unpin> Iterator for Iter<St> {
+    type Item = St::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.modio.block_on(self.stream.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
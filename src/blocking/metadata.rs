@@ -0,0 +1,32 @@
+use super::Modio;
+use crate::metadata::MetadataMap;
+use crate::{Deletion, Result};
+
+/// Blocking interface to manage the metadata key value pairs of a mod.
+#[derive(Clone)]
+pub struct Metadata {
+    modio: Modio,
+    inner: crate::metadata::Metadata,
+}
+
+impl Metadata {
+    pub(crate) fn new(modio: Modio, inner: crate::metadata::Metadata) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Return the metadata key value pairs for a mod that this `Metadata` refers to.
+    pub fn get(self) -> Result<MetadataMap> {
+        self.modio.block_on(self.inner.get())
+    }
+
+    /// Add metadata for a mod that this `Metadata` refers to. [required: token]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, metadata: MetadataMap) -> Result<()> {
+        self.modio.block_on(self.inner.add(metadata))
+    }
+
+    /// Delete metadata for a mod that this `Metadata` refers to. [required: token]
+    pub fn delete(self, metadata: MetadataMap) -> Result<Deletion> {
+        self.modio.block_on(self.inner.delete(metadata))
+    }
+}
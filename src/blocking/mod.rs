@@ -0,0 +1,176 @@
+//! A blocking client API.
+//!
+//! The blocking [`Modio`] client wraps an async [`Modio`](crate::Modio) client and runs its
+//! requests on an internal current-thread runtime. The interfaces mirror the async API with
+//! synchronous methods.
+//!
+//! The blocking client must not be used within an async runtime.
+//!
+//! # Example
+//! ```no_run
+//! use modio::blocking::Modio;
+//! use modio::filter::prelude::*;
+//! use modio::types::id::Id;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let modio = Modio::new("api-key")?;
+//!
+//! // OpenXcom: The X-Com Files
+//! let mod_ = modio.mod_(Id::new(51), Id::new(158)).get()?;
+//! println!("{}", mod_.name);
+//!
+//! let filter = Fulltext::eq("tftd").limit(10);
+//! for mod_ in modio.game(Id::new(51)).mods().search(filter).iter()? {
+//!     let mod_ = mod_?;
+//!     println!("{}. {}", mod_.id, mod_.name);
+//! }
+//! #     Ok(())
+//! # }
+//! ```
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::runtime::{Builder as RuntimeBuilder, Runtime};
+
+use crate::auth::{Credentials, Token};
+use crate::download::DownloadAction;
+use crate::error::{self, Result};
+use crate::types::id::{GameId, ModId};
+
+mod auth;
+mod comments;
+mod download;
+mod files;
+mod games;
+mod loader;
+mod metadata;
+mod mods;
+mod reports;
+mod teams;
+mod user;
+
+pub use self::auth::{Auth, EmailFlow};
+pub use self::comments::Comments;
+pub use self::download::Downloader;
+pub use self::files::{FileRef, Files};
+pub use self::games::{GameRef, Games, Tags as GameTags};
+pub use self::loader::Query;
+pub use self::metadata::Metadata;
+pub use self::mods::{Dependencies, ModRef, Mods, Tags as ModTags};
+pub use self::reports::Reports;
+pub use self::teams::Members;
+pub use self::user::Me;
+
+/// Blocking endpoint interface to interacting with the [mod.io](https://mod.io) API.
+#[derive(Clone, Debug)]
+pub struct Modio {
+    inner: crate::Modio,
+    rt: Arc<Runtime>,
+}
+
+impl Modio {
+    /// Create an endpoint to [https://api.mod.io/v1](https://docs.mod.io/#mod-io-api-v1).
+    pub fn new<C>(credentials: C) -> Result<Self>
+    where
+        C: Into<Credentials>,
+    {
+        crate::Modio::new(credentials).and_then(Self::from_async)
+    }
+
+    /// Create an endpoint to a different host.
+    pub fn host<H, C>(host: H, credentials: C) -> Result<Self>
+    where
+        H: Into<String>,
+        C: Into<Credentials>,
+    {
+        crate::Modio::host(host, credentials).and_then(Self::from_async)
+    }
+
+    /// Create a blocking endpoint from an async client configured with a
+    /// [`Builder`](crate::Builder).
+    pub fn from_async(modio: crate::Modio) -> Result<Self> {
+        let rt = RuntimeBuilder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(error::builder)?;
+        Ok(Self {
+            inner: modio,
+            rt: Arc::new(rt),
+        })
+    }
+
+    /// Returns the wrapped async client.
+    pub fn as_async(&self) -> &crate::Modio {
+        &self.inner
+    }
+
+    /// Return an endpoint with new credentials.
+    #[must_use]
+    pub fn with_credentials<CR>(&self, credentials: CR) -> Self
+    where
+        CR: Into<Credentials>,
+    {
+        Self {
+            inner: self.inner.with_credentials(credentials),
+            rt: Arc::clone(&self.rt),
+        }
+    }
+
+    /// Return an endpoint with a new token.
+    #[must_use]
+    pub fn with_token<T>(&self, token: T) -> Self
+    where
+        T: Into<Token>,
+    {
+        Self {
+            inner: self.inner.with_token(token),
+            rt: Arc::clone(&self.rt),
+        }
+    }
+
+    /// Return a reference to an interface for requesting access tokens.
+    pub fn auth(&self) -> Auth {
+        Auth::new(self.clone(), self.inner.auth())
+    }
+
+    /// Return a reference to an interface that provides access to game information.
+    pub fn games(&self) -> Games {
+        Games::new(self.clone(), self.inner.games())
+    }
+
+    /// Return a reference to a game.
+    pub fn game(&self, game_id: GameId) -> GameRef {
+        GameRef::new(self.clone(), self.inner.game(game_id))
+    }
+
+    /// Return a reference to a mod.
+    pub fn mod_(&self, game_id: GameId, mod_id: ModId) -> ModRef {
+        ModRef::new(self.clone(), self.inner.mod_(game_id, mod_id))
+    }
+
+    /// Returns a [`Downloader`] for saving the mod file to a local file or retrieving its data.
+    ///
+    /// See [`Modio::download`](crate::Modio::download).
+    pub fn download<A>(&self, action: A) -> Result<Downloader>
+    where
+        DownloadAction: From<A>,
+    {
+        let downloader = self.block_on(self.inner.download(action))?;
+        Ok(Downloader::new(self.clone(), downloader))
+    }
+
+    /// Return a reference to an interface that provides access to resources owned by the user
+    /// associated with the current authentication credentials.
+    pub fn user(&self) -> Me {
+        Me::new(self.clone(), self.inner.user())
+    }
+
+    /// Return a reference to an interface to report games, mods and users.
+    pub fn reports(&self) -> Reports {
+        Reports::new(self.clone(), self.inner.reports())
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.rt.block_on(future)
+    }
+}
@@ -0,0 +1,234 @@
+use super::loader::Iter;
+use super::{Comments, FileRef, Files, Members, Metadata, Modio, Query};
+use crate::filter::Filter;
+use crate::mods::{
+    AddMediaOptions, AddModOptions, DeleteMediaOptions, EditDependenciesOptions, EditModOptions,
+    EditTagsOptions, Rating, ReorderMediaOptions,
+};
+use crate::types::id::{FileId, ModId};
+use crate::types::mods::{Dependency, Event, Mod, Statistics, Tag};
+use crate::{Deletion, Editing, Result};
+
+/// Blocking interface for mods of a game.
+#[derive(Clone)]
+pub struct Mods {
+    modio: Modio,
+    inner: crate::mods::Mods,
+}
+
+impl Mods {
+    pub(crate) fn new(modio: Modio, inner: crate::mods::Mods) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Returns a `Query` interface to retrieve mods.
+    ///
+    /// See [Filters and sorting](crate::mods::filters).
+    pub fn search(&self, filter: Filter) -> Query<Mod> {
+        Query::new(self.modio.clone(), self.inner.search(filter))
+    }
+
+    /// Return a reference to a mod.
+    pub fn get(&self, id: ModId) -> ModRef {
+        ModRef::new(self.modio.clone(), self.inner.get(id))
+    }
+
+    /// Add a mod and return the newly created Modio mod object. [required: token]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, options: AddModOptions) -> Result<Mod> {
+        self.modio.block_on(self.inner.add(options))
+    }
+
+    /// Returns a `Query` interface to retrieve the statistics for all mods of a game.
+    ///
+    /// See [Filters and sorting](crate::mods::filters::stats).
+    pub fn statistics(self, filter: Filter) -> Query<Statistics> {
+        Query::new(self.modio, self.inner.statistics(filter))
+    }
+
+    /// Returns a `Query` interface to retrieve the event log of all mods of the game sorted by
+    /// latest event first.
+    ///
+    /// See [Filters and sorting](crate::mods::filters::events).
+    pub fn events(self, filter: Filter) -> Query<Event> {
+        Query::new(self.modio, self.inner.events(filter))
+    }
+}
+
+/// Blocking reference interface of a mod.
+#[derive(Clone)]
+pub struct ModRef {
+    modio: Modio,
+    inner: crate::mods::ModRef,
+}
+
+impl ModRef {
+    pub(crate) fn new(modio: Modio, inner: crate::mods::ModRef) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Get a reference to the Modio mod object that this `ModRef` refers to.
+    pub fn get(self) -> Result<Mod> {
+        self.modio.block_on(self.inner.get())
+    }
+
+    /// Return a reference to an interface that provides access to the files of a mod.
+    pub fn files(&self) -> Files {
+        Files::new(self.modio.clone(), self.inner.files())
+    }
+
+    /// Return a reference to a file of a mod.
+    pub fn file(&self, id: FileId) -> FileRef {
+        FileRef::new(self.modio.clone(), self.inner.file(id))
+    }
+
+    /// Return a reference to an interface to manage metadata key value pairs of a mod.
+    pub fn metadata(&self) -> Metadata {
+        Metadata::new(self.modio.clone(), self.inner.metadata())
+    }
+
+    /// Return a reference to an interface to manage the tags of a mod.
+    pub fn tags(&self) -> Tags {
+        Tags::new(self.modio.clone(), self.inner.tags())
+    }
+
+    /// Return a reference to an interface that provides access to the comments of a mod.
+    pub fn comments(&self) -> Comments {
+        Comments::new(self.modio.clone(), self.inner.comments())
+    }
+
+    /// Return a reference to an interface to manage the dependencies of a mod.
+    pub fn dependencies(&self) -> Dependencies {
+        Dependencies::new(self.modio.clone(), self.inner.dependencies())
+    }
+
+    /// Return the statistics for a mod.
+    pub fn statistics(self) -> Result<Statistics> {
+        self.modio.block_on(self.inner.statistics())
+    }
+
+    /// Returns a `Query` interface to retrieve the event log for a mod sorted by latest event
+    /// first.
+    ///
+    /// See [Filters and sorting](crate::mods::filters::events).
+    pub fn events(self, filter: Filter) -> Query<Event> {
+        Query::new(self.modio, self.inner.events(filter))
+    }
+
+    /// Return a reference to an interface to manage team members of a mod.
+    pub fn members(&self) -> Members {
+        Members::new(self.modio.clone(), self.inner.members())
+    }
+
+    /// Edit details for a mod. [required: token]
+    pub fn edit(self, options: EditModOptions) -> Result<Editing<Mod>> {
+        self.modio.block_on(self.inner.edit(options))
+    }
+
+    /// Delete a mod. [required: token]
+    pub fn delete(self) -> Result<()> {
+        self.modio.block_on(self.inner.delete())
+    }
+
+    /// Add new media to a mod. [required: token]
+    pub fn add_media(self, options: AddMediaOptions) -> Result<()> {
+        self.modio.block_on(self.inner.add_media(options))
+    }
+
+    /// Delete media from a mod. [required: token]
+    pub fn delete_media(self, options: DeleteMediaOptions) -> Result<Deletion> {
+        self.modio.block_on(self.inner.delete_media(options))
+    }
+
+    /// Reorder images, sketchfab or youtube links from a mod profile. [required: token]
+    pub fn reorder_media(self, options: ReorderMediaOptions) -> Result<()> {
+        self.modio.block_on(self.inner.reorder_media(options))
+    }
+
+    /// Submit a positive or negative rating for a mod. [required: token]
+    pub fn rate(self, rating: Rating) -> Result<()> {
+        self.modio.block_on(self.inner.rate(rating))
+    }
+
+    /// Subscribe the authenticated user to a mod. [required: token]
+    pub fn subscribe(self) -> Result<()> {
+        self.modio.block_on(self.inner.subscribe())
+    }
+
+    /// Unsubscribe the authenticated user from a mod. [required: token]
+    pub fn unsubscribe(self) -> Result<()> {
+        self.modio.block_on(self.inner.unsubscribe())
+    }
+}
+
+/// Blocking interface for dependencies.
+#[derive(Clone)]
+pub struct Dependencies {
+    modio: Modio,
+    inner: crate::mods::Dependencies,
+}
+
+impl Dependencies {
+    fn new(modio: Modio, inner: crate::mods::Dependencies) -> Self {
+        Self { modio, inner }
+    }
+
+    /// List mod dependencies.
+    pub fn list(self) -> Result<Vec<Dependency>> {
+        self.modio.block_on(self.inner.list())
+    }
+
+    /// Provides an iterator over all mod dependencies.
+    #[allow(clippy::iter_not_returning_iterator)]
+    pub fn iter(self) -> Result<impl Iterator<Item = Result<Dependency>>> {
+        let stream = self.modio.block_on(self.inner.iter())?;
+        Ok(Iter::new(self.modio, Box::pin(stream)))
+    }
+
+    /// Add mod dependencies. [required: token]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, options: EditDependenciesOptions) -> Result<()> {
+        self.modio.block_on(self.inner.add(options))
+    }
+
+    /// Delete mod dependencies. [required: token]
+    pub fn delete(self, options: EditDependenciesOptions) -> Result<Deletion> {
+        self.modio.block_on(self.inner.delete(options))
+    }
+}
+
+/// Blocking interface for the tags of a mod.
+#[derive(Clone)]
+pub struct Tags {
+    modio: Modio,
+    inner: crate::mods::Tags,
+}
+
+impl Tags {
+    fn new(modio: Modio, inner: crate::mods::Tags) -> Self {
+        Self { modio, inner }
+    }
+
+    /// List all mod tags.
+    pub fn list(self) -> Result<Vec<Tag>> {
+        self.modio.block_on(self.inner.list())
+    }
+
+    /// Provides an iterator over all mod tags.
+    #[allow(clippy::iter_not_returning_iterator)]
+    pub fn iter(self) -> Result<impl Iterator<Item = Result<Tag>>> {
+        let stream = self.modio.block_on(self.inner.iter())?;
+        Ok(Iter::new(self.modio, Box::pin(stream)))
+    }
+
+    /// Add mod tags. [required: token]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, options: EditTagsOptions) -> Result<()> {
+        self.modio.block_on(self.inner.add(options))
+    }
+
+    /// Delete mod tags. [required: token]
+    pub fn delete(self, options: EditTagsOptions) -> Result<Deletion> {
+        self.modio.block_on(self.inner.delete(options))
+    }
+}
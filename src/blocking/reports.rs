@@ -0,0 +1,21 @@
+use super::Modio;
+use crate::reports::Report;
+use crate::Result;
+
+/// Blocking interface to report games, mods and users.
+#[derive(Clone)]
+pub struct Reports {
+    modio: Modio,
+    inner: crate::reports::Reports,
+}
+
+impl Reports {
+    pub(crate) fn new(modio: Modio, inner: crate::reports::Reports) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Submit a report for any resource on mod.io. [required: token]
+    pub fn submit(self, report: Report) -> Result<()> {
+        self.modio.block_on(self.inner.submit(report))
+    }
+}
@@ -0,0 +1,23 @@
+use super::{Modio, Query};
+use crate::filter::Filter;
+use crate::teams::TeamMember;
+
+/// Blocking interface for the team members of a mod.
+#[derive(Clone)]
+pub struct Members {
+    modio: Modio,
+    inner: crate::teams::Members,
+}
+
+impl Members {
+    pub(crate) fn new(modio: Modio, inner: crate::teams::Members) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Returns a `Query` interface to retrieve all team members.
+    ///
+    /// See [Filters and sorting](crate::teams::filters).
+    pub fn search(&self, filter: Filter) -> Query<TeamMember> {
+        Query::new(self.modio.clone(), self.inner.search(filter))
+    }
+}
@@ -0,0 +1,93 @@
+use super::{Modio, Query};
+use crate::filter::Filter;
+use crate::types::files::File;
+use crate::types::games::Game;
+use crate::types::id::UserId;
+use crate::types::mods::{Mod, Rating};
+use crate::types::{Event, User};
+use crate::Result;
+
+/// Blocking interface for resources owned by the authenticated user or is team member of.
+#[derive(Clone)]
+pub struct Me {
+    modio: Modio,
+    inner: crate::user::Me,
+}
+
+impl Me {
+    pub(crate) fn new(modio: Modio, inner: crate::user::Me) -> Self {
+        Self { modio, inner }
+    }
+
+    /// Returns the current user if authenticated.
+    pub fn current(self) -> Result<Option<User>> {
+        self.modio.block_on(self.inner.current())
+    }
+
+    /// Returns a `Query` interface to retrieve all games the authenticated user added or
+    /// is team member of. [required: token]
+    ///
+    /// See [Filters and sorting](crate::user::filters::games).
+    pub fn games(&self, filter: Filter) -> Query<Game> {
+        Query::new(self.modio.clone(), self.inner.games(filter))
+    }
+
+    /// Returns a `Query` interface to retrieve all mods the authenticated user added or
+    /// is team member of. [required: token]
+    ///
+    /// See [Filters and sorting](crate::user::filters::mods).
+    pub fn mods(&self, filter: Filter) -> Query<Mod> {
+        Query::new(self.modio.clone(), self.inner.mods(filter))
+    }
+
+    /// Returns a `Query` interface to retrieve all modfiles the authenticated user uploaded.
+    /// [required: token]
+    ///
+    /// See [Filters and sorting](crate::user::filters::files).
+    pub fn files(&self, filter: Filter) -> Query<File> {
+        Query::new(self.modio.clone(), self.inner.files(filter))
+    }
+
+    /// Returns a `Query` interface to retrieve the events that have been fired specific to the
+    /// authenticated user. [required: token]
+    ///
+    /// See [Filters and sorting](crate::user::filters::events).
+    pub fn events(self, filter: Filter) -> Query<Event> {
+        Query::new(self.modio, self.inner.events(filter))
+    }
+
+    /// Returns a `Query` interface to retrieve the mods the authenticated user is subscribed to.
+    /// [required: token]
+    ///
+    /// See [Filters and sorting](crate::user::filters::subscriptions).
+    pub fn subscriptions(self, filter: Filter) -> Query<Mod> {
+        Query::new(self.modio, self.inner.subscriptions(filter))
+    }
+
+    /// Returns a `Query` interface to retrieve the mod ratings submitted by the authenticated user.
+    /// [required: token]
+    ///
+    /// See [Filters and sorting](crate::user::filters::ratings).
+    pub fn ratings(self, filter: Filter) -> Query<Rating> {
+        Query::new(self.modio, self.inner.ratings(filter))
+    }
+
+    /// Get all users muted by the authenticated user. [required: token]
+    pub fn muted_users(self) -> Query<User> {
+        Query::new(self.modio, self.inner.muted_users())
+    }
+
+    /// Mute a user. [required: token]
+    ///
+    /// This will prevent mod.io from returning mods authored by the muted user.
+    pub fn mute_user(self, user_id: UserId) -> Result<()> {
+        self.modio.block_on(self.inner.mute_user(user_id))
+    }
+
+    /// Unmute a previously muted user. [required: token]
+    ///
+    /// This will re-enable mod.io return mods authored by the muted user again.
+    pub fn unmute_user(self, user_id: UserId) -> Result<()> {
+        self.modio.block_on(self.inner.unmute_user(user_id))
+    }
+}
@@ -0,0 +1,426 @@
+//! Client-side caching of `GET` responses.
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use http::header::{HeaderMap, HeaderValue, AUTHORIZATION, CACHE_CONTROL, ETAG};
+use serde_json::Value;
+use tracing::trace;
+use url::{form_urlencoded, Position, Url};
+
+use crate::transport::Request;
+
+/// Configuration of the response cache.
+///
+/// Successful `GET` responses are stored with the `ETag` header and an expiry time. Fresh
+/// responses are returned without a request to the API. Stale responses with an `ETag` are
+/// revalidated with a conditional request.
+///
+/// The expiry time is the `max-age` directive of the `Cache-Control` header, limited by the
+/// earliest `date_expires` field of the response, e.g. of [mod statistics] and
+/// [download links]. Responses with neither are fresh for the default TTL.
+///
+/// Responses with `Cache-Control: no-store` are never stored, `private` responses are stored
+/// since the cache is not shared with other users. Responses are stored separately for every
+/// host, api key and access token.
+///
+/// Successful `POST`, `PUT` and `DELETE` requests remove the cached responses of the path, its
+/// subpaths and its parent path, e.g. adding a comment removes the cached comments and the
+/// cached mod.
+///
+/// [mod statistics]: crate::types::mods::Statistics
+/// [download links]: crate::types::files::Download
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use modio::cache::Cache;
+/// use modio::Modio;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let modio = Modio::builder("api-key")
+///     .cache(Cache::memory().default_ttl(Duration::from_secs(60)))
+///     .build()?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Cache {
+    store: Arc<dyn CacheStore>,
+    default_ttl: Duration,
+}
+
+impl Cache {
+    /// Create a new cache backed by a [`CacheStore`].
+    pub fn new<S: CacheStore + 'static>(store: S) -> Self {
+        Self {
+            store: Arc::new(store),
+            default_ttl: Duration::ZERO,
+        }
+    }
+
+    /// Create a new cache backed by a [`MemoryStore`].
+    pub fn memory() -> Self {
+        Self::new(MemoryStore::new())
+    }
+
+    /// Set how long responses without a `Cache-Control` header or `date_expires` field are
+    /// considered fresh.
+    ///
+    /// Defaults to zero, which revalidates every cached response.
+    #[must_use]
+    pub fn default_ttl(self, ttl: Duration) -> Self {
+        Self {
+            default_ttl: ttl,
+            ..self
+        }
+    }
+
+    /// Returns the cache key of a request.
+    ///
+    /// Only `GET` requests are cached.
+    pub(crate) fn key(req: &Request) -> Option<String> {
+        if req.method() != http::Method::GET {
+            return None;
+        }
+        cache_key(req)
+    }
+
+    /// Returns the cache key and the cached response for a key returned by [`Cache::key`].
+    pub(crate) async fn lookup(&self, key: String) -> (String, Option<CachedResponse>) {
+        let cached = self.store.get(&key).await;
+        (key, cached)
+    }
+
+    /// Remove the cached responses that are affected by a successful mutation of `uri`.
+    pub(crate) async fn invalidate(&self, uri: &http::Uri) {
+        let url = match Url::parse(&uri.to_string()) {
+            Ok(url) => url,
+            Err(_) => return,
+        };
+        let origin = &url[..Position::BeforePath];
+        let path = url.path().trim_end_matches('/');
+        trace!("invalidating cached responses of {origin}{path}");
+        self.store.remove_prefix(&format!("{origin}{path}?")).await;
+        self.store.remove_prefix(&format!("{origin}{path}/")).await;
+        if let Some((parent, _)) = path.rsplit_once('/') {
+            self.store
+                .remove_prefix(&format!("{origin}{parent}?"))
+                .await;
+        }
+    }
+
+    /// Store a successful response.
+    pub(crate) async fn store(&self, key: &str, headers: &HeaderMap, body: Bytes) {
+        let directives = CacheControl::from_headers(headers);
+        if directives.no_store {
+            self.store.remove(key).await;
+            return;
+        }
+        let etag = headers
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        let expires = self.expires(&directives, &body);
+        trace!("caching response of {key} until {expires}");
+        let response = CachedResponse {
+            body,
+            etag,
+            expires,
+        };
+        self.store.put(key, response).await;
+    }
+
+    /// Refresh the expiry time of a revalidated response.
+    pub(crate) async fn refresh(&self, key: &str, headers: &HeaderMap, cached: &CachedResponse) {
+        let directives = CacheControl::from_headers(headers);
+        let response = CachedResponse {
+            expires: self.expires(&directives, &cached.body),
+            ..cached.clone()
+        };
+        self.store.put(key, response).await;
+    }
+
+    fn expires(&self, directives: &CacheControl, body: &[u8]) -> u64 {
+        let now = unix_now();
+        if directives.no_cache {
+            return now;
+        }
+        let date_expires = min_date_expires(body);
+        match (directives.max_age, date_expires) {
+            (Some(max_age), Some(date_expires)) => date_expires.min(now + max_age),
+            (Some(max_age), None) => now + max_age,
+            (None, Some(date_expires)) => date_expires,
+            (None, None) => now + self.default_ttl.as_secs(),
+        }
+    }
+}
+
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cache")
+            .field("default_ttl", &self.default_ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A cached response.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct CachedResponse {
+    /// The response body.
+    pub body: Bytes,
+    /// The `ETag` header of the response.
+    pub etag: Option<String>,
+    /// Unix timestamp until the response is fresh.
+    pub expires: u64,
+}
+
+impl CachedResponse {
+    /// Create a cached response, e.g. when loading it from an external [`CacheStore`].
+    pub fn new(body: Bytes, etag: Option<String>, expires: u64) -> Self {
+        Self {
+            body,
+            etag,
+            expires,
+        }
+    }
+
+    /// Returns `true` if the response can be used without revalidation.
+    pub fn is_fresh(&self) -> bool {
+        self.expires > unix_now()
+    }
+
+    /// Returns the `If-None-Match` header value for a conditional request.
+    pub(crate) fn if_none_match(&self) -> Option<HeaderValue> {
+        self.etag
+            .as_deref()
+            .and_then(|etag| HeaderValue::from_str(etag).ok())
+    }
+}
+
+/// Storage of cached responses.
+///
+/// Implement this trait to share the cache between processes, e.g. with Redis.
+pub trait CacheStore: Send + Sync {
+    /// Returns the cached response of a key.
+    fn get(&self, key: &str) -> BoxFuture<'_, Option<CachedResponse>>;
+
+    /// Store the response of a key.
+    fn put(&self, key: &str, response: CachedResponse) -> BoxFuture<'_, ()>;
+
+    /// Remove the response of a key.
+    fn remove(&self, key: &str) -> BoxFuture<'_, ()>;
+
+    /// Remove the responses of all keys that start with `prefix`.
+    ///
+    /// Keys start with the url of the request without the query, so a prefix selects the
+    /// responses of a path.
+    fn remove_prefix(&self, prefix: &str) -> BoxFuture<'_, ()>;
+}
+
+/// In-memory store of cached responses.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove all cached responses.
+    pub fn clear(&self) {
+        self.entries.lock().expect("cache poisoned").clear();
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> BoxFuture<'_, Option<CachedResponse>> {
+        let response = self
+            .entries
+            .lock()
+            .expect("cache poisoned")
+            .get(key)
+            .cloned();
+        Box::pin(async move { response })
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) -> BoxFuture<'_, ()> {
+        let mut entries = self.entries.lock().expect("cache poisoned");
+        entries.retain(|_, r| r.etag.is_some() || r.is_fresh());
+        entries.insert(key.to_owned(), response);
+        Box::pin(async {})
+    }
+
+    fn remove(&self, key: &str) -> BoxFuture<'_, ()> {
+        self.entries.lock().expect("cache poisoned").remove(key);
+        Box::pin(async {})
+    }
+
+    fn remove_prefix(&self, prefix: &str) -> BoxFuture<'_, ()> {
+        let mut entries = self.entries.lock().expect("cache poisoned");
+        entries.retain(|key, _| !key.starts_with(prefix));
+        Box::pin(async {})
+    }
+}
+
+impl<S: CacheStore + ?Sized> CacheStore for Arc<S> {
+    fn get(&self, key: &str) -> BoxFuture<'_, Option<CachedResponse>> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) -> BoxFuture<'_, ()> {
+        (**self).put(key, response)
+    }
+
+    fn remove(&self, key: &str) -> BoxFuture<'_, ()> {
+        (**self).remove(key)
+    }
+
+    fn remove_prefix(&self, prefix: &str) -> BoxFuture<'_, ()> {
+        (**self).remove_prefix(prefix)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct CacheControl {
+    max_age: Option<u64>,
+    no_cache: bool,
+    no_store: bool,
+}
+
+impl CacheControl {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let mut directives = CacheControl::default();
+        let values = headers
+            .get_all(CACHE_CONTROL)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','));
+
+        for directive in values {
+            let (name, value) = match directive.split_once('=') {
+                Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+                None => (directive.trim(), None),
+            };
+            match name.to_ascii_lowercase().as_str() {
+                "max-age" => directives.max_age = value.and_then(|v| v.parse().ok()),
+                "no-cache" => directives.no_cache = true,
+                "no-store" => directives.no_store = true,
+                _ => {}
+            }
+        }
+        directives
+    }
+}
+
+/// The cache key consists of the url of the request without the api key, followed by a hash
+/// of the api key and the access token.
+fn cache_key(req: &Request) -> Option<String> {
+    let url = Url::parse(&req.uri().to_string()).ok()?;
+    let mut credentials = md5::Context::new();
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    for (k, v) in url.query_pairs() {
+        if k == "api_key" {
+            credentials.consume(v.as_bytes());
+        } else {
+            query.append_pair(&k, &v);
+        }
+    }
+    let query = query.finish();
+    if let Some(token) = req.headers().get(AUTHORIZATION) {
+        credentials.consume(b"\n");
+        credentials.consume(token.as_bytes());
+    }
+    let url = &url[..Position::AfterPath];
+    Some(format!("{url}?{query}#{:x}", credentials.compute()))
+}
+
+/// Returns the earliest `date_expires` field of a JSON response.
+fn min_date_expires(body: &[u8]) -> Option<u64> {
+    fn visit(value: &Value, min: &mut Option<u64>) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    match (key.as_str(), value.as_u64()) {
+                        ("date_expires", Some(expires)) if expires > 0 => {
+                            *min = Some(min.map_or(expires, |m| m.min(expires)));
+                        }
+                        _ => visit(value, min),
+                    }
+                }
+            }
+            Value::Array(values) => values.iter().for_each(|v| visit(v, min)),
+            _ => {}
+        }
+    }
+    let value = serde_json::from_slice(body).ok()?;
+    let mut min = None;
+    visit(&value, &mut min);
+    min
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{HeaderMap, HeaderValue, CACHE_CONTROL};
+
+    use super::{min_date_expires, CacheControl};
+
+    #[test]
+    fn parse_cache_control() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CACHE_CONTROL,
+            HeaderValue::from_static("public, max-age=60, no-cache"),
+        );
+        let directives = CacheControl::from_headers(&headers);
+        assert_eq!(
+            directives,
+            CacheControl {
+                max_age: Some(60),
+                no_cache: true,
+                no_store: false,
+            }
+        );
+
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+        assert!(CacheControl::from_headers(&headers).no_store);
+        // The cache is private to the client.
+        headers.insert(
+            CACHE_CONTROL,
+            HeaderValue::from_static("private, max-age=30"),
+        );
+        assert!(!CacheControl::from_headers(&headers).no_store);
+        assert_eq!(
+            CacheControl::from_headers(&HeaderMap::new()),
+            CacheControl::default()
+        );
+    }
+
+    #[test]
+    fn earliest_date_expires() {
+        let body = br#"{
+            "data": [
+                {"mod_id": 1, "date_expires": 300},
+                {"mod_id": 2, "modfile": {"download": {"binary_url": "", "date_expires": 200}}}
+            ],
+            "result_count": 2
+        }"#;
+        assert_eq!(min_date_expires(body), Some(200));
+        assert_eq!(min_date_expires(br#"{"id": 1}"#), None);
+        assert_eq!(min_date_expires(b"not json"), None);
+    }
+}
@@ -1,12 +1,27 @@
 use std::convert::TryInto;
+use std::future::Future;
+#[cfg(feature = "testing")]
+use std::path::Path;
 use std::sync::Arc;
 
 use http::header::USER_AGENT;
 use http::header::{HeaderMap, HeaderValue};
+#[cfg(feature = "__tls")]
 use reqwest::{Client, ClientBuilder, Proxy};
 
-use crate::auth::Credentials;
+use crate::auth::store::CredentialStore;
+use crate::auth::{Credentials, ReauthenticateFn, Session};
+use crate::cache::Cache;
 use crate::error::{self, Error, Result};
+#[cfg(feature = "tower")]
+use crate::middleware::{self, BoxService, LayerFn};
+use crate::ratelimit::{Limiter, RateLimit};
+use crate::retry::RetryPolicy;
+#[cfg(feature = "testing")]
+use crate::testing::cassette;
+#[cfg(feature = "__tls")]
+use crate::transport::ReqwestTransport;
+use crate::transport::Transport;
 use crate::{TargetPlatform, TargetPortal};
 
 use super::{ClientRef, Modio};
@@ -21,9 +36,21 @@ pub struct Builder {
 struct Config {
     host: Option<String>,
     credentials: Credentials,
+    reauthenticate: Option<ReauthenticateFn>,
+    credential_store: Option<Arc<dyn CredentialStore>>,
+    #[cfg(feature = "__tls")]
     builder: Option<ClientBuilder>,
     headers: HeaderMap,
+    #[cfg(feature = "__tls")]
     proxies: Vec<Proxy>,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<RateLimit>,
+    cache: Option<Cache>,
+    transport: Option<Arc<dyn Transport>>,
+    #[cfg(feature = "testing")]
+    cassette: Option<cassette::Mode>,
+    #[cfg(feature = "tower")]
+    layers: Vec<LayerFn>,
     #[cfg(feature = "__tls")]
     tls: TlsBackend,
     error: Option<Error>,
@@ -61,9 +88,21 @@ impl Builder {
             config: Config {
                 host: None,
                 credentials: credentials.into(),
+                reauthenticate: None,
+                credential_store: None,
+                #[cfg(feature = "__tls")]
                 builder: None,
                 headers: HeaderMap::new(),
+                #[cfg(feature = "__tls")]
                 proxies: Vec::new(),
+                retry_policy: RetryPolicy::default(),
+                rate_limit: None,
+                cache: None,
+                transport: None,
+                #[cfg(feature = "testing")]
+                cassette: None,
+                #[cfg(feature = "tower")]
+                layers: Vec::new(),
                 #[cfg(feature = "__tls")]
                 tls: TlsBackend::default(),
                 error: None,
@@ -82,46 +121,67 @@ impl Builder {
         let host = config.host.unwrap_or_else(|| DEFAULT_HOST.to_string());
         let credentials = config.credentials;
 
-        let client = {
-            let mut builder = {
-                let builder = config.builder.unwrap_or_else(Client::builder);
-                #[cfg(feature = "__tls")]
-                match config.tls {
-                    #[cfg(feature = "default-tls")]
-                    TlsBackend::Default => builder.use_native_tls(),
-                    #[cfg(feature = "rustls-tls")]
-                    TlsBackend::Rustls => builder.use_rustls_tls(),
-                }
-
-                #[cfg(not(feature = "__tls"))]
-                builder
-            };
-
+        let headers = {
             let mut headers = config.headers;
             if !headers.contains_key(USER_AGENT) {
                 headers.insert(USER_AGENT, HeaderValue::from_static(DEFAULT_AGENT));
             }
+            headers
+        };
 
-            for proxy in config.proxies {
-                builder = builder.proxy(proxy);
-            }
+        let transport = match config.transport {
+            Some(transport) => transport,
+            #[cfg(feature = "__tls")]
+            None => {
+                let mut builder = {
+                    let builder = config.builder.unwrap_or_else(Client::builder);
+                    match config.tls {
+                        #[cfg(feature = "default-tls")]
+                        TlsBackend::Default => builder.use_native_tls(),
+                        #[cfg(feature = "rustls-tls")]
+                        TlsBackend::Rustls => builder.use_rustls_tls(),
+                    }
+                };
+
+                for proxy in config.proxies {
+                    builder = builder.proxy(proxy);
+                }
 
-            builder
-                .default_headers(headers)
-                .build()
-                .map_err(error::builder)?
+                let client = builder.build().map_err(error::builder)?;
+                Arc::new(ReqwestTransport::new(client))
+            }
+            #[cfg(not(feature = "__tls"))]
+            None => {
+                return Err(error::builder(
+                    "a transport is required without the `default-tls` or `rustls-tls` feature",
+                ));
+            }
         };
+        #[cfg(feature = "testing")]
+        let transport = match config.cassette {
+            Some(mode) => mode.apply(transport).map_err(error::builder)?,
+            None => transport,
+        };
+        #[cfg(feature = "tower")]
+        let transport = middleware::apply(transport, config.layers);
 
         Ok(Modio {
             inner: Arc::new(ClientRef {
                 host,
-                client,
-                credentials,
+                headers,
+                transport,
+                session: Session::new(credentials, config.reauthenticate, config.credential_store),
+                retry_policy: config.retry_policy,
+                limiter: config.rate_limit.map(|r| Arc::new(Limiter::new(r))),
+                cache: config.cache,
             }),
         })
     }
 
     /// Configure the underlying `reqwest` client using `reqwest::ClientBuilder`.
+    ///
+    /// The client is only used to send requests if no custom [`Transport`] is set.
+    #[cfg(feature = "__tls")]
     pub fn client<F>(mut self, f: F) -> Builder
     where
         F: FnOnce(ClientBuilder) -> ClientBuilder,
@@ -164,11 +224,156 @@ impl Builder {
     }
 
     /// Add a `Proxy` to the list of proxies the client will use.
+    #[cfg(feature = "__tls")]
     pub fn proxy(mut self, proxy: Proxy) -> Builder {
         self.config.proxies.push(proxy);
         self
     }
 
+    /// Set the callback to re-authenticate when the access token has expired.
+    ///
+    /// The callback is invoked before a request that requires a token is sent with an expired
+    /// token and when a request fails with `401 Unauthorized`. The returned credentials replace
+    /// the credentials of the client and all its clones, and the request is retried once.
+    /// Concurrent requests wait for the running callback instead of invoking it again.
+    ///
+    /// The callback is called with a client that has no access token.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::auth::SteamOptions;
+    /// use modio::Modio;
+    /// # fn steam_ticket() -> String { String::new() }
+    ///
+    /// # fn main() -> modio::Result<()> {
+    /// let modio = Modio::builder("api-key")
+    ///     .on_token_expired(|modio: Modio| async move {
+    ///         let opts = SteamOptions::new(steam_ticket()).terms_agreed(true);
+    ///         modio.auth().external(opts).await
+    ///     })
+    ///     .build()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn on_token_expired<F, Fut>(mut self, f: F) -> Builder
+    where
+        F: Fn(Modio) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Credentials>> + Send + 'static,
+    {
+        self.config.reauthenticate = Some(Session::reauthenticate_fn(f));
+        self
+    }
+
+    /// Set the [`CredentialStore`] that saves the credentials returned by the authentication
+    /// endpoints and the [`on_token_expired`](Builder::on_token_expired) callback.
+    ///
+    /// Disabled by default.
+    pub fn credential_store<S: CredentialStore + 'static>(mut self, store: S) -> Builder {
+        self.config.credential_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Load the saved credentials from the [`CredentialStore`] and use it as the credential
+    /// store of the client.
+    ///
+    /// Only the saved access token is restored, the api key passed to [`Builder::new`] is always
+    /// kept. Saved credentials of a different api key are ignored. Errors are returned by
+    /// [`Builder::build`].
+    ///
+    /// See the [`store`](crate::auth::store) module for an example.
+    pub async fn load_credentials<S: CredentialStore + 'static>(mut self, store: S) -> Builder {
+        match store.load().await {
+            Ok(Some(saved)) if saved.api_key == self.config.credentials.api_key => {
+                if saved.token.is_some() {
+                    self.config.credentials.token = saved.token;
+                }
+            }
+            Ok(_) => {}
+            Err(e) => self.config.error = Some(e),
+        }
+        self.credential_store(store)
+    }
+
+    /// Set the [`RetryPolicy`] for failed requests.
+    ///
+    /// Defaults to [`RetryPolicy::never`].
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Builder {
+        self.config.retry_policy = policy;
+        self
+    }
+
+    /// Enable the client-side [`RateLimit`] shared by all clones of the client.
+    ///
+    /// Disabled by default.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Builder {
+        self.config.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Enable the [`Cache`] of `GET` responses shared by all clones of the client.
+    ///
+    /// Disabled by default.
+    pub fn cache(mut self, cache: Cache) -> Builder {
+        self.config.cache = Some(cache);
+        self
+    }
+
+    /// Set the [`Transport`] that sends the requests.
+    ///
+    /// Defaults to `ReqwestTransport` with the configured `reqwest` client if the `default-tls`
+    /// or `rustls-tls` feature is enabled. Without these features a transport is required.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Builder {
+        self.config.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Record every request and response to a cassette file.
+    ///
+    /// The cassette is a JSON file that is rewritten after each exchange. The api key and the
+    /// access tokens are replaced with `REDACTED`. Response bodies are buffered in memory before
+    /// they are written, including downloads.
+    ///
+    /// Use [`Builder::replay`] to serve the recorded responses in tests.
+    #[cfg(feature = "testing")]
+    pub fn record<P: AsRef<Path>>(mut self, path: P) -> Builder {
+        let path = path.as_ref().to_path_buf();
+        self.config.cassette = Some(cassette::Mode::Record(path));
+        self
+    }
+
+    /// Serve the responses from a cassette file recorded with [`Builder::record`] without
+    /// sending any requests.
+    ///
+    /// Requests are matched by method and uri. Identical requests are answered in the recorded
+    /// order and the last matching response is repeated once all of them are used. Requests
+    /// without a recorded response fail with a request error.
+    #[cfg(feature = "testing")]
+    pub fn replay<P: AsRef<Path>>(mut self, path: P) -> Builder {
+        let path = path.as_ref().to_path_buf();
+        self.config.cassette = Some(cassette::Mode::Replay(path));
+        self
+    }
+
+    /// Add a tower [`Layer`](tower::Layer) to the request pipeline.
+    ///
+    /// The layers wrap the [`Transport`]. The first added layer is the outermost layer.
+    /// See the [`middleware`](crate::middleware) module for an example.
+    #[cfg(feature = "tower")]
+    pub fn layer<L>(mut self, layer: L) -> Builder
+    where
+        L: tower::Layer<BoxService> + Send + 'static,
+        L::Service: tower::Service<crate::transport::Request, Response = crate::transport::Response>
+            + Clone
+            + Send
+            + 'static,
+        <L::Service as tower::Service<crate::transport::Request>>::Error:
+            Into<crate::transport::BoxError>,
+        <L::Service as tower::Service<crate::transport::Request>>::Future: Send + 'static,
+    {
+        self.config.layers.push(middleware::layer_fn(layer));
+        self
+    }
+
     /// Set the target platform.
     ///
     /// See the [mod.io docs](https://docs.mod.io/#targeting-a-platform) for more information.
@@ -1,15 +1,19 @@
 use std::sync::Arc;
 
-use reqwest::Client;
+use http::header::HeaderMap;
 
-use crate::auth::{Auth, Credentials, Token};
+use crate::auth::{Auth, Credentials, Session, Token};
+use crate::cache::Cache;
 use crate::download::{DownloadAction, Downloader};
 use crate::error::Result;
 use crate::games::{GameRef, Games};
 use crate::mods::ModRef;
+use crate::ratelimit::Limiter;
 use crate::reports::Reports;
 use crate::request::RequestBuilder;
+use crate::retry::RetryPolicy;
 use crate::routing::Route;
+use crate::transport::Transport;
 use crate::types::id::{GameId, ModId};
 use crate::user::Me;
 
@@ -30,8 +34,12 @@ pub struct Modio {
 #[derive(Debug)]
 pub(crate) struct ClientRef {
     pub(crate) host: String,
-    pub(crate) client: Client,
-    pub(crate) credentials: Credentials,
+    pub(crate) headers: HeaderMap,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) session: Session,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) limiter: Option<Arc<Limiter>>,
+    pub(crate) cache: Option<Cache>,
 }
 
 impl Modio {
@@ -60,21 +68,38 @@ impl Modio {
     }
 
     /// Return an endpoint with new credentials.
+    ///
+    /// The endpoint shares the rate limiter if the api key is unchanged and uses a new rate
+    /// limiter for a different api key.
     #[must_use]
     pub fn with_credentials<CR>(&self, credentials: CR) -> Self
     where
         CR: Into<Credentials>,
     {
+        let credentials = credentials.into();
+        let limiter = self.inner.limiter.as_ref().map(|l| {
+            if self.inner.session.credentials().api_key == credentials.api_key {
+                Arc::clone(l)
+            } else {
+                Arc::new(l.reset())
+            }
+        });
         Self {
             inner: Arc::new(ClientRef {
                 host: self.inner.host.clone(),
-                client: self.inner.client.clone(),
-                credentials: credentials.into(),
+                headers: self.inner.headers.clone(),
+                transport: Arc::clone(&self.inner.transport),
+                session: self.inner.session.with_credentials(credentials),
+                retry_policy: self.inner.retry_policy.clone(),
+                limiter,
+                cache: self.inner.cache.clone(),
             }),
         }
     }
 
     /// Return an endpoint with a new token.
+    ///
+    /// The endpoint shares the rate limiter of the api key.
     #[must_use]
     pub fn with_token<T>(&self, token: T) -> Self
     where
@@ -83,15 +108,27 @@ impl Modio {
         Self {
             inner: Arc::new(ClientRef {
                 host: self.inner.host.clone(),
-                client: self.inner.client.clone(),
-                credentials: Credentials {
-                    api_key: self.inner.credentials.api_key.clone(),
+                headers: self.inner.headers.clone(),
+                transport: Arc::clone(&self.inner.transport),
+                session: self.inner.session.with_credentials(Credentials {
+                    api_key: self.inner.session.credentials().api_key,
                     token: Some(token.into()),
-                },
+                }),
+                retry_policy: self.inner.retry_policy.clone(),
+                limiter: self.inner.limiter.clone(),
+                cache: self.inner.cache.clone(),
             }),
         }
     }
 
+    /// Returns the current credentials of the client.
+    ///
+    /// The credentials change when an expired access token is replaced by the callback set with
+    /// [`Builder::on_token_expired`].
+    pub fn credentials(&self) -> Credentials {
+        self.inner.session.credentials()
+    }
+
     /// Return a reference to an interface for requesting access tokens.
     pub fn auth(&self) -> Auth {
         Auth::new(self.clone())
@@ -197,3 +234,33 @@ impl Modio {
         RequestBuilder::new(self.clone(), route)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::auth::Token;
+    use crate::ratelimit::RateLimit;
+    use crate::Modio;
+
+    #[test]
+    fn derived_clients_share_limiter_of_api_key() {
+        let modio = Modio::builder("foo")
+            .rate_limit(RateLimit::per_minute(60))
+            .build()
+            .unwrap();
+        let limiter = |m: &Modio| m.inner.limiter.clone().expect("limiter");
+
+        let with_token = modio.with_token(Token {
+            value: "token".to_owned(),
+            expired_at: None,
+        });
+        assert!(Arc::ptr_eq(&limiter(&modio), &limiter(&with_token)));
+
+        let same_key = modio.with_credentials(("foo", "token"));
+        assert!(Arc::ptr_eq(&limiter(&modio), &limiter(&same_key)));
+
+        let other_key = modio.with_credentials("bar");
+        assert!(!Arc::ptr_eq(&limiter(&modio), &limiter(&other_key)));
+    }
+}
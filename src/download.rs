@@ -2,28 +2,139 @@
 use std::error::Error as StdError;
 use std::fmt;
 use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
-use bytes::Bytes;
-use futures_util::{SinkExt, Stream, StreamExt, TryFutureExt, TryStreamExt};
-use reqwest::{Method, Response, StatusCode};
-use tokio::fs::File as AsyncFile;
+use bytes::{Bytes, BytesMut};
+use futures_util::{future, SinkExt, Stream, StreamExt, TryFutureExt, TryStreamExt};
+use http::header::{CONTENT_RANGE, RANGE};
+use http::{Method, StatusCode};
+use pin_project_lite::pin_project;
+use tokio::fs::{File as AsyncFile, OpenOptions};
 use tokio::io::BufWriter;
 use tokio_util::codec::{BytesCodec, FramedWrite};
+use tokio_util::io::ReaderStream;
 use tracing::debug;
+use url::Url;
 
 use crate::error::{self, Result};
+use crate::response::ResponseMeta;
+use crate::transport::{self, Body, Response};
 use crate::types::files::File;
 use crate::types::id::{FileId, GameId, ModId};
 use crate::types::mods::Mod;
+use crate::types::ErrorResponse;
 use crate::Modio;
 
 /// A `Downloader` can be used to stream a mod file or save the file to a local file.
 /// Constructed with [`Modio::download`].
-pub struct Downloader(Response);
+///
+/// The request for the mod file is sent when the data is consumed with
+/// [`save_to_file`](Self::save_to_file), [`resume_to_file`](Self::resume_to_file),
+/// [`bytes`](Self::bytes) or [`stream`](Self::stream).
+pub struct Downloader {
+    modio: Modio,
+    file: Box<File>,
+    verify: bool,
+    progress: Option<ProgressFn>,
+}
+
+type ProgressFn = Box<dyn FnMut(&Progress) + Send>;
 
 impl Downloader {
     pub(crate) async fn new(modio: Modio, action: DownloadAction) -> Result<Self> {
-        Ok(Self(request_file(modio, action).await?))
+        let file = resolve_file(&modio, action).await?;
+        Ok(Self {
+            modio,
+            file: Box::new(file),
+            verify: false,
+            progress: None,
+        })
+    }
+
+    /// Verify the size and the MD5 hash of the downloaded data against the metadata of the
+    /// mod file.
+    ///
+    /// The hash is computed while streaming. A mismatch is returned as error with
+    /// [`Error::SizeMismatch`] or [`Error::ChecksumMismatch`] as source after the last chunk
+    /// of data has been received. The data written to a local file is not removed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let action = modio::DownloadAction::Primary {
+    ///     game_id: Id::new(5),
+    ///     mod_id: Id::new(19),
+    /// };
+    ///
+    /// modio
+    ///     .download(action)
+    ///     .await?
+    ///     .verify()
+    ///     .save_to_file("mod.zip")
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn verify(self) -> Self {
+        Self {
+            verify: true,
+            ..self
+        }
+    }
+
+    /// Register a callback that is called with the [`Progress`] of the download after each
+    /// received chunk of data.
+    ///
+    /// The callback is used by [`save_to_file`](Self::save_to_file),
+    /// [`resume_to_file`](Self::resume_to_file), [`bytes`](Self::bytes) and
+    /// [`stream`](Self::stream).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let action = modio::DownloadAction::Primary {
+    ///     game_id: Id::new(5),
+    ///     mod_id: Id::new(19),
+    /// };
+    ///
+    /// modio
+    ///     .download(action)
+    ///     .await?
+    ///     .on_progress(|p| {
+    ///         println!(
+    ///             "{}/{:?} bytes, {:.0} bytes/s, eta: {:?}",
+    ///             p.received(),
+    ///             p.total(),
+    ///             p.throughput(),
+    ///             p.eta(),
+    ///         );
+    ///     })
+    ///     .save_to_file("mod.zip")
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn on_progress<F>(self, callback: F) -> Self
+    where
+        F: FnMut(&Progress) + Send + 'static,
+    {
+        Self {
+            progress: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Returns the metadata of the mod file that is downloaded.
+    pub fn file(&self) -> &File {
+        &self.file
     }
 
     /// Save the mod file to a local file.
@@ -47,11 +158,91 @@ impl Downloader {
     /// # }
     /// ```
     pub async fn save_to_file<P: AsRef<Path>>(self, file: P) -> Result<()> {
+        let stream = self.send().await?;
         let out = AsyncFile::create(file).map_err(error::decode).await?;
-        let out = BufWriter::with_capacity(512 * 512, out);
-        let out = FramedWrite::new(out, BytesCodec::new());
-        let out = SinkExt::<Bytes>::sink_map_err(out, error::decode);
-        self.stream().forward(out).await
+        write_to_file(stream, out).await
+    }
+
+    /// Save the mod file to a local file and resume a previously interrupted download.
+    ///
+    /// If the local file already exists, only the missing part of the mod file is requested with
+    /// a HTTP `Range` request and appended to the file. The download falls back to a full
+    /// download if the server ignores the range request or the local file is larger than
+    /// the mod file.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let action = modio::DownloadAction::Primary {
+    ///     game_id: Id::new(5),
+    ///     mod_id: Id::new(19),
+    /// };
+    ///
+    /// modio
+    ///     .download(action)
+    ///     .await?
+    ///     .resume_to_file("mod.zip")
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn resume_to_file<P: AsRef<Path>>(self, file: P) -> Result<()> {
+        let file = file.as_ref();
+        let offset = match tokio::fs::metadata(file).await {
+            Ok(m) if m.is_file() => m.len(),
+            _ => 0,
+        };
+        let total = self.content_length();
+
+        if offset == 0 || total.map_or(false, |total| offset > total) {
+            return self.save_to_file(file).await;
+        }
+        let mut verifier = self.verifier();
+        if total == Some(offset) {
+            debug!("download already completed: {}", file.display());
+            return match verifier {
+                Some(mut verifier) => {
+                    verifier.update_from_file(file).await?;
+                    verifier.finish()
+                }
+                None => Ok(()),
+            };
+        }
+
+        let Self {
+            modio,
+            file: meta,
+            progress,
+            ..
+        } = self;
+        let url = meta.download.binary_url;
+        debug!("resuming download at offset {offset}: {url}");
+        let response = send_request(&modio, url.clone(), Some(offset)).await?;
+
+        if content_range_start(&response) == Some(offset) {
+            if let Some(verifier) = &mut verifier {
+                verifier.update_from_file(file).await?;
+            }
+            let out = OpenOptions::new()
+                .append(true)
+                .open(file)
+                .map_err(error::decode)
+                .await?;
+            let progress = progress.map(|f| ProgressTracker::new(f, offset, &response));
+            write_to_file(download_stream(response, verifier, progress), out).await
+        } else {
+            debug!("range request not satisfied, restarting download");
+            let response = if response.status() == StatusCode::OK {
+                response
+            } else {
+                request_file(&modio, url, None).await?
+            };
+            let out = AsyncFile::create(file).map_err(error::decode).await?;
+            let progress = progress.map(|f| ProgressTracker::new(f, 0, &response));
+            write_to_file(download_stream(response, verifier, progress), out).await
+        }
     }
 
     /// Get the full mod file as `Bytes`.
@@ -71,7 +262,15 @@ impl Downloader {
     /// # }
     /// ```
     pub async fn bytes(self) -> Result<Bytes> {
-        self.0.bytes().map_err(error::request).await
+        let capacity = self.content_length().unwrap_or_default();
+        let buf = BytesMut::with_capacity(usize::try_from(capacity).unwrap_or_default());
+        self.stream()
+            .try_fold(buf, |mut buf, chunk| {
+                buf.extend_from_slice(&chunk);
+                future::ok(buf)
+            })
+            .await
+            .map(BytesMut::freeze)
     }
 
     /// `Stream` of bytes of the mod file.
@@ -96,10 +295,10 @@ impl Downloader {
     /// # }
     /// ```
     pub fn stream(self) -> impl Stream<Item = Result<Bytes>> {
-        self.0.bytes_stream().map_err(error::request)
+        self.send().try_flatten_stream()
     }
 
-    /// Get the content length from the mod file response.
+    /// Get the size of the mod file from its metadata.
     ///
     /// # Example
     /// ```no_run
@@ -115,17 +314,227 @@ impl Downloader {
     ///     .download(action)
     ///     .await?
     ///     .content_length()
-    ///     .expect("mod file response should have content length");
+    ///     .expect("mod file should have a file size");
     /// #     Ok(())
     /// # }
     /// ```
     pub fn content_length(&self) -> Option<u64> {
-        self.0.content_length()
+        Some(self.file.filesize).filter(|size| *size > 0)
+    }
+
+    /// Request the full mod file.
+    async fn send(self) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let verifier = self.verifier();
+        let url = self.file.download.binary_url.clone();
+        let response = request_file(&self.modio, url, None).await?;
+        let progress = self.progress.map(|f| ProgressTracker::new(f, 0, &response));
+        Ok(download_stream(response, verifier, progress))
+    }
+
+    fn verifier(&self) -> Option<Verifier> {
+        self.verify.then(|| Verifier::new(&self.file))
     }
 }
 
-async fn request_file(modio: Modio, action: DownloadAction) -> Result<Response> {
-    let url = match action {
+async fn write_to_file<S>(stream: S, out: AsyncFile) -> Result<()>
+where
+    S: Stream<Item = Result<Bytes>>,
+{
+    let out = BufWriter::with_capacity(512 * 512, out);
+    let out = FramedWrite::new(out, BytesCodec::new());
+    let out = SinkExt::<Bytes>::sink_map_err(out, error::decode);
+    stream.forward(out).await
+}
+
+/// Progress of a download reported by [`Downloader::on_progress`].
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    received: u64,
+    total: Option<u64>,
+    offset: u64,
+    elapsed: Duration,
+}
+
+impl Progress {
+    /// Returns the number of bytes received, including the bytes of a resumed download.
+    pub fn received(&self) -> u64 {
+        self.received
+    }
+
+    /// Returns the total size of the mod file if known.
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Returns the time elapsed since the download started.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the average download speed in bytes per second.
+    pub fn throughput(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 {
+            (self.received - self.offset) as f64 / secs
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the estimated time remaining based on the average download speed.
+    pub fn eta(&self) -> Option<Duration> {
+        let remaining = self.total?.saturating_sub(self.received);
+        let throughput = self.throughput();
+        if throughput > 0.0 {
+            Some(Duration::from_secs_f64(remaining as f64 / throughput))
+        } else {
+            None
+        }
+    }
+}
+
+struct ProgressTracker {
+    callback: ProgressFn,
+    started: Instant,
+    progress: Progress,
+}
+
+impl ProgressTracker {
+    fn new(callback: ProgressFn, offset: u64, response: &Response) -> Self {
+        Self {
+            callback,
+            started: Instant::now(),
+            progress: Progress {
+                received: offset,
+                total: transport::content_length(response).map(|len| offset + len),
+                offset,
+                elapsed: Duration::ZERO,
+            },
+        }
+    }
+
+    fn update(&mut self, len: usize) {
+        self.progress.received += len as u64;
+        self.progress.elapsed = self.started.elapsed();
+        (self.callback)(&self.progress);
+    }
+}
+
+/// Computes the size and the MD5 hash of the downloaded data.
+struct Verifier {
+    file_id: FileId,
+    filesize: u64,
+    md5: String,
+    size: u64,
+    context: md5::Context,
+}
+
+impl Verifier {
+    fn new(file: &File) -> Self {
+        Self {
+            file_id: file.id,
+            filesize: file.filesize,
+            md5: file.filehash.md5.clone(),
+            size: 0,
+            context: md5::Context::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.size += data.len() as u64;
+        self.context.consume(data);
+    }
+
+    async fn update_from_file(&mut self, file: &Path) -> Result<()> {
+        let file = AsyncFile::open(file).map_err(error::decode).await?;
+        let mut st = ReaderStream::new(file);
+        while let Some(chunk) = st.try_next().map_err(error::decode).await? {
+            self.update(&chunk);
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        if self.size != self.filesize {
+            return Err(error::download(Error::SizeMismatch {
+                file_id: self.file_id,
+                expected: self.filesize,
+                actual: self.size,
+            }));
+        }
+        let actual = format!("{:x}", self.context.compute());
+        if !actual.eq_ignore_ascii_case(&self.md5) {
+            return Err(error::download(Error::ChecksumMismatch {
+                file_id: self.file_id,
+                expected: self.md5,
+                actual,
+            }));
+        }
+        Ok(())
+    }
+}
+
+pin_project! {
+    struct DownloadStream<St> {
+        #[pin]
+        stream: St,
+        verifier: Option<Verifier>,
+        progress: Option<ProgressTracker>,
+    }
+}
+
+fn download_stream(
+    response: Response,
+    verifier: Option<Verifier>,
+    progress: Option<ProgressTracker>,
+) -> impl Stream<Item = Result<Bytes>> {
+    DownloadStream {
+        stream: response.into_body().into_stream().map_err(error::request),
+        verifier,
+        progress,
+    }
+}
+
+impl<St: Stream<Item = Result<Bytes>>> Stream for DownloadStream<St> {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(Ok(bytes))) => {
+                if let Some(verifier) = this.verifier {
+                    verifier.update(&bytes);
+                }
+                if let Some(progress) = this.progress {
+                    progress.update(bytes.len());
+                }
+                Poll::Ready(Some(Ok(bytes)))
+            }
+            Poll::Ready(None) => match this.verifier.take().map(Verifier::finish) {
+                Some(Err(e)) => Poll::Ready(Some(Err(e))),
+                _ => Poll::Ready(None),
+            },
+            other => other,
+        }
+    }
+}
+
+/// Returns the first byte position of the `Content-Range` header of a partial response.
+fn content_range_start(response: &Response) -> Option<u64> {
+    if response.status() != StatusCode::PARTIAL_CONTENT {
+        return None;
+    }
+    response
+        .headers()
+        .get(CONTENT_RANGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("bytes "))
+        .and_then(|v| v.split('-').next())
+        .and_then(|v| v.parse().ok())
+}
+
+async fn resolve_file(modio: &Modio, action: DownloadAction) -> Result<File> {
+    let file = match action {
         DownloadAction::Primary { game_id, mod_id } => {
             let modref = modio.mod_(game_id, mod_id);
             let m = modref
@@ -139,20 +548,20 @@ async fn request_file(modio: Modio, action: DownloadAction) -> Result<Response>
                 })
                 .await?;
             if let Some(file) = m.modfile {
-                file.download.binary_url
+                file
             } else {
                 let source = Error::NoPrimaryFile { game_id, mod_id };
                 return Err(error::download(source));
             }
         }
-        DownloadAction::FileObj(file) => file.download.binary_url,
+        DownloadAction::FileObj(file) => *file,
         DownloadAction::File {
             game_id,
             mod_id,
             file_id,
         } => {
             let fileref = modio.mod_(game_id, mod_id).file(file_id);
-            let file = fileref
+            fileref
                 .get()
                 .map_err(|e| match e.status() {
                     Some(StatusCode::NOT_FOUND) => {
@@ -165,8 +574,7 @@ async fn request_file(modio: Modio, action: DownloadAction) -> Result<Response>
                     }
                     _ => e,
                 })
-                .await?;
-            file.download.binary_url
+                .await?
         }
         DownloadAction::Version {
             game_id,
@@ -216,7 +624,7 @@ async fn request_file(modio: Modio, action: DownloadAction) -> Result<Response>
             };
 
             if let Some(file) = file {
-                file.download.binary_url
+                file
             } else {
                 let source = error.expect("bug in previous match!");
                 return Err(error::download(source));
@@ -224,16 +632,34 @@ async fn request_file(modio: Modio, action: DownloadAction) -> Result<Response>
         }
     };
 
+    Ok(file)
+}
+
+async fn request_file(modio: &Modio, url: Url, offset: Option<u64>) -> Result<Response> {
+    let response = send_request(modio, url, offset).await?;
+    let status = response.status();
+    if status.is_client_error() || status.is_server_error() {
+        let meta = ResponseMeta::new(status, response.headers());
+        let body = response.into_body().bytes().await.unwrap_or_default();
+        let error = match serde_json::from_slice::<ErrorResponse>(&body) {
+            Ok(mer) => error::error_for_status(status, mer.error),
+            Err(_) => error::status(status),
+        };
+        return Err(error.with_meta(meta));
+    }
+    Ok(response)
+}
+
+async fn send_request(modio: &Modio, url: Url, offset: Option<u64>) -> Result<Response> {
     debug!("downloading file: {}", url);
-    modio
-        .inner
-        .client
-        .request(Method::GET, url)
-        .send()
-        .map_err(error::builder_or_request)
-        .await?
-        .error_for_status()
-        .map_err(error::request)
+    let mut req = http::Request::builder()
+        .method(Method::GET)
+        .uri(url.as_str());
+    if let Some(offset) = offset {
+        req = req.header(RANGE, format!("bytes={offset}-"));
+    }
+    let req = req.body(Body::empty()).map_err(error::builder)?;
+    transport::send(modio, req).await
 }
 
 /// Defines the action that is performed for [`Modio::download`].
@@ -293,6 +719,18 @@ pub enum Error {
         mod_id: ModId,
         version: String,
     },
+    /// The size of the downloaded data doesn't match the size of the file.
+    SizeMismatch {
+        file_id: FileId,
+        expected: u64,
+        actual: u64,
+    },
+    /// The MD5 hash of the downloaded data doesn't match the hash of the file.
+    ChecksumMismatch {
+        file_id: FileId,
+        expected: String,
+        actual: String,
+    },
 }
 
 impl StdError for Error {}
@@ -332,6 +770,22 @@ impl fmt::Display for Error {
                 fmt,
                 "Mod {{id: {mod_id}, game_id: {game_id}}}: No file with version '{version}' found.",
             ),
+            Error::SizeMismatch {
+                file_id,
+                expected,
+                actual,
+            } => write!(
+                fmt,
+                "File {{ id: {file_id} }}: Size mismatch, expected {expected} bytes but received {actual} bytes.",
+            ),
+            Error::ChecksumMismatch {
+                file_id,
+                expected,
+                actual,
+            } => write!(
+                fmt,
+                "File {{ id: {file_id} }}: MD5 hash mismatch, expected '{expected}' but computed '{actual}'.",
+            ),
         }
     }
 }
@@ -3,8 +3,9 @@ use std::error::Error as StdError;
 use std::fmt;
 use std::time::Duration;
 
-use reqwest::StatusCode;
+use http::StatusCode;
 
+use crate::response::ResponseMeta;
 use crate::types::Error as ApiError;
 
 /// A `Result` alias where the `Err` case is `modio::Error`.
@@ -20,6 +21,7 @@ type BoxError = Box<dyn StdError + Send + Sync>;
 struct Inner {
     kind: Kind,
     error_ref: Option<u16>,
+    meta: Option<ResponseMeta>,
     source: Option<BoxError>,
 }
 
@@ -30,6 +32,7 @@ impl Error {
             inner: Box::new(Inner {
                 kind,
                 error_ref: None,
+                meta: None,
                 source: None,
             }),
         }
@@ -47,6 +50,15 @@ impl Error {
         self
     }
 
+    #[inline]
+    pub(crate) fn with_meta(mut self, meta: ResponseMeta) -> Self {
+        if self.inner.error_ref.is_none() {
+            self.inner.error_ref = meta.error_ref();
+        }
+        self.inner.meta = Some(meta);
+        self
+    }
+
     /// Returns true if the API key/access token is incorrect, revoked, expired or the request
     /// needs a different authentication method.
     pub fn is_auth(&self) -> bool {
@@ -74,6 +86,11 @@ impl Error {
         matches!(self.inner.kind, Kind::RateLimit { .. })
     }
 
+    /// Returns true if the error is related to the http request.
+    pub fn is_request(&self) -> bool {
+        matches!(self.inner.kind, Kind::Request)
+    }
+
     /// Returns true if the error was generated from a response.
     pub fn is_response(&self) -> bool {
         matches!(self.inner.kind, Kind::Response { .. })
@@ -89,10 +106,21 @@ impl Error {
         matches!(self.inner.kind, Kind::Decode)
     }
 
+    /// Returns true if the error is from reading or writing a local file.
+    pub fn is_io(&self) -> bool {
+        matches!(self.inner.kind, Kind::Io)
+    }
+
+    /// Returns true if the error is from reading or writing a zip archive.
+    #[cfg(feature = "zip")]
+    pub fn is_archive(&self) -> bool {
+        matches!(self.inner.kind, Kind::Archive)
+    }
+
     /// Returns the API error if the error was generated from a response.
     pub fn api_error(&self) -> Option<&ApiError> {
         match &self.inner.kind {
-            Kind::Response { error, .. } => Some(error),
+            Kind::Response { error, .. } => error.as_ref(),
             _ => None,
         }
     }
@@ -104,6 +132,26 @@ impl Error {
         self.inner.error_ref
     }
 
+    /// Returns the metadata of the response if the error was generated from a response.
+    pub fn response_meta(&self) -> Option<&ResponseMeta> {
+        self.inner.meta.as_ref()
+    }
+
+    /// Returns the request id assigned by mod.io if the error was generated from a response.
+    ///
+    /// Include the request id in support requests to mod.io.
+    pub fn request_id(&self) -> Option<&str> {
+        self.inner.meta.as_ref().and_then(ResponseMeta::request_id)
+    }
+
+    /// Returns the duration to wait before retrying if the rate limit has been exhausted.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self.inner.kind {
+            Kind::RateLimit { retry_after } => Some(retry_after),
+            _ => None,
+        }
+    }
+
     /// Returns status code if the error was generated from a response.
     pub fn status(&self) -> Option<StatusCode> {
         match self.inner.kind {
@@ -132,6 +180,9 @@ impl fmt::Debug for Error {
         if let Some(ref error_ref) = self.inner.error_ref {
             builder.field("error_ref", error_ref);
         }
+        if let Some(request_id) = self.request_id() {
+            builder.field("request_id", &request_id);
+        }
 
         if let Some(ref source) = self.inner.source {
             builder.field("source", source);
@@ -148,6 +199,9 @@ impl fmt::Display for Error {
             Kind::TermsAcceptanceRequired => f.write_str("terms acceptance is required")?,
             Kind::Builder => f.write_str("builder error")?,
             Kind::Decode => f.write_str("error decoding response body")?,
+            Kind::Io => f.write_str("I/O error")?,
+            #[cfg(feature = "zip")]
+            Kind::Archive => f.write_str("zip archive error")?,
             Kind::Download => f.write_str("download error")?,
             Kind::Request => f.write_str("http request error")?,
             Kind::Response { status, .. } => {
@@ -202,23 +256,18 @@ pub(crate) enum Kind {
     Request,
     Response {
         status: StatusCode,
-        error: ApiError,
+        error: Option<ApiError>,
     },
     Decode,
+    Io,
+    #[cfg(feature = "zip")]
+    Archive,
 }
 
 pub(crate) fn token_required() -> Error {
     Error::new(Kind::TokenRequired)
 }
 
-pub(crate) fn builder_or_request(e: reqwest::Error) -> Error {
-    if e.is_builder() {
-        builder(e)
-    } else {
-        request(e)
-    }
-}
-
 pub(crate) fn builder<E: Into<BoxError>>(source: E) -> Error {
     Error::new(Kind::Builder).with(source)
 }
@@ -227,10 +276,26 @@ pub(crate) fn request<E: Into<BoxError>>(source: E) -> Error {
     Error::new(Kind::Request).with(source)
 }
 
+pub(crate) fn validation<S: Into<String>>(message: S, errors: Vec<(String, String)>) -> Error {
+    Error::new(Kind::Validation {
+        message: message.into(),
+        errors,
+    })
+}
+
 pub(crate) fn decode<E: Into<BoxError>>(source: E) -> Error {
     Error::new(Kind::Decode).with(source)
 }
 
+pub(crate) fn io<E: Into<BoxError>>(source: E) -> Error {
+    Error::new(Kind::Io).with(source)
+}
+
+#[cfg(feature = "zip")]
+pub(crate) fn archive<E: Into<BoxError>>(source: E) -> Error {
+    Error::new(Kind::Archive).with(source)
+}
+
 pub(crate) fn error_for_status(status: StatusCode, error: ApiError) -> Error {
     let error_ref = error.error_ref;
     let kind = match status {
@@ -240,11 +305,22 @@ pub(crate) fn error_for_status(status: StatusCode, error: ApiError) -> Error {
         },
         StatusCode::UNAUTHORIZED => Kind::Unauthorized,
         StatusCode::FORBIDDEN if error_ref == 11051 => Kind::TermsAcceptanceRequired,
-        _ => Kind::Response { status, error },
+        _ => Kind::Response {
+            status,
+            error: Some(error),
+        },
     };
     Error::new(kind).with_error_ref(error_ref)
 }
 
+/// Error for a response with a client or server error status and no API error object.
+pub(crate) fn status(status: StatusCode) -> Error {
+    Error::new(Kind::Response {
+        status,
+        error: None,
+    })
+}
+
 pub(crate) fn ratelimit(retry_after: u64) -> Error {
     Error::new(Kind::RateLimit {
         retry_after: Duration::from_secs(retry_after),
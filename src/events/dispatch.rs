@@ -0,0 +1,384 @@
+//! Local dispatcher of mod events.
+//!
+//! The [`Dispatcher`] polls the mod events of one or more games and calls the handlers registered
+//! for the game and event type. The position in the event log of every game is saved in a
+//! [`CheckpointStore`] after the handlers of an event have succeeded.
+//!
+//! Delivery is at-least-once: if a handler fails, the checkpoint is not advanced and the event is
+//! delivered again to all of its handlers with the next poll. Handlers should be idempotent.
+//!
+//! An event whose handler keeps failing blocks the later events of its game. By default the
+//! event is delivered again until the handler succeeds. Use [`Dispatcher::max_attempts`] to skip
+//! the event after a number of failed attempts and [`Dispatcher::on_dead_letter`] to be notified
+//! about the skipped events.
+//!
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use modio::events::dispatch::{Dispatcher, FileCheckpointStore};
+//! use modio::mods::{Event, EventType};
+//! use modio::types::id::Id;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #     let modio = modio::Modio::new("api-key")?;
+//! let dispatcher = Dispatcher::new(modio)
+//!     .checkpoint_store(FileCheckpointStore::new("checkpoints.json"))
+//!     .interval(Duration::from_secs(30))
+//!     .on(Id::new(51), EventType::MODFILE_CHANGED, |event: Event| async move {
+//!         println!("new modfile for mod {}", event.mod_id);
+//!         Ok::<_, std::io::Error>(())
+//!     })
+//!     .on(Id::new(51), EventType::MOD_DELETED, |event: Event| async move {
+//!         println!("mod {} deleted", event.mod_id);
+//!         Ok::<_, std::io::Error>(())
+//!     });
+//!
+//! dispatcher.run().await?;
+//! #     Ok(())
+//! # }
+//! ```
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error as StdError;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+use futures_util::{FutureExt, TryFutureExt};
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::{debug, error, warn};
+
+use super::{fetch, is_transient, Cursor};
+use crate::fs;
+use crate::prelude::*;
+use crate::types::id::{EventId, GameId};
+use crate::types::mods::{Event, EventType};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+type Handler = Arc<dyn Fn(Event) -> BoxFuture<'static, Result<(), BoxError>> + Send + Sync>;
+type DeadLetterFn = Box<dyn Fn(&Event, &(dyn StdError + Send + Sync)) + Send + Sync>;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Storage of the event log positions of a [`Dispatcher`].
+///
+/// The checkpoints are stored by game.
+pub trait CheckpointStore: Send + Sync {
+    /// Load the checkpoint of a game.
+    fn load(&self, game_id: GameId) -> BoxFuture<'_, Result<Option<Cursor>>>;
+
+    /// Save the checkpoint of a game.
+    fn save(&self, game_id: GameId, cursor: Cursor) -> BoxFuture<'_, Result<()>>;
+}
+
+impl<S: CheckpointStore + ?Sized> CheckpointStore for Arc<S> {
+    fn load(&self, game_id: GameId) -> BoxFuture<'_, Result<Option<Cursor>>> {
+        (**self).load(game_id)
+    }
+
+    fn save(&self, game_id: GameId, cursor: Cursor) -> BoxFuture<'_, Result<()>> {
+        (**self).save(game_id, cursor)
+    }
+}
+
+/// In-memory checkpoint store. This is the default store of a [`Dispatcher`].
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoints: Mutex<HashMap<GameId, Cursor>>,
+}
+
+impl MemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load(&self, game_id: GameId) -> BoxFuture<'_, Result<Option<Cursor>>> {
+        let checkpoints = self.checkpoints.lock().expect("checkpoints poisoned");
+        let cursor = checkpoints.get(&game_id).copied();
+        Box::pin(async move { Ok(cursor) })
+    }
+
+    fn save(&self, game_id: GameId, cursor: Cursor) -> BoxFuture<'_, Result<()>> {
+        let mut checkpoints = self.checkpoints.lock().expect("checkpoints poisoned");
+        checkpoints.insert(game_id, cursor);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Checkpoint store that keeps the checkpoints of all games in a JSON file.
+#[derive(Debug)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl FileCheckpointStore {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    async fn read(&self) -> Result<BTreeMap<GameId, Cursor>> {
+        let checkpoints = fs::read_json(&self.path).await?;
+        Ok(checkpoints.unwrap_or_default())
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn load(&self, game_id: GameId) -> BoxFuture<'_, Result<Option<Cursor>>> {
+        Box::pin(async move {
+            let _guard = self.lock.lock().await;
+            Ok(self.read().await?.get(&game_id).copied())
+        })
+    }
+
+    fn save(&self, game_id: GameId, cursor: Cursor) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async move {
+            let _guard = self.lock.lock().await;
+            let mut checkpoints = self.read().await?;
+            checkpoints.insert(game_id, cursor);
+            fs::write_json_atomic(&self.path, &checkpoints).await
+        })
+    }
+}
+
+struct Registration {
+    game_id: GameId,
+    event_type: Option<EventType>,
+    handler: Handler,
+}
+
+/// Dispatches the polled mod events to the registered handlers.
+///
+/// See the [module documentation](self) for an example.
+pub struct Dispatcher {
+    modio: Modio,
+    store: Arc<dyn CheckpointStore>,
+    interval: Duration,
+    handlers: Vec<Registration>,
+    max_attempts: Option<u32>,
+    dead_letter: Option<DeadLetterFn>,
+    attempts: Mutex<HashMap<EventId, u32>>,
+}
+
+impl Dispatcher {
+    pub fn new(modio: Modio) -> Self {
+        Self {
+            modio,
+            store: Arc::new(MemoryCheckpointStore::new()),
+            interval: DEFAULT_INTERVAL,
+            handlers: Vec::new(),
+            max_attempts: None,
+            dead_letter: None,
+            attempts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set the store of the event log positions.
+    ///
+    /// Games without a checkpoint start with the events added from now on.
+    #[must_use]
+    pub fn checkpoint_store<S: CheckpointStore + 'static>(self, store: S) -> Self {
+        Self {
+            store: Arc::new(store),
+            ..self
+        }
+    }
+
+    /// Set the interval between two polls of the event logs.
+    ///
+    /// Defaults to 30 seconds.
+    #[must_use]
+    pub fn interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    /// Skip an event after `max_attempts` failed deliveries instead of delivering it again.
+    ///
+    /// The skipped event is passed to the [`on_dead_letter`](Self::on_dead_letter) callback.
+    /// The attempts are counted in memory and start again when the dispatcher is recreated.
+    ///
+    /// Defaults to no limit.
+    #[must_use]
+    pub fn max_attempts(self, max_attempts: u32) -> Self {
+        Self {
+            max_attempts: Some(max_attempts.max(1)),
+            ..self
+        }
+    }
+
+    /// Set a callback that is called with the events skipped after
+    /// [`max_attempts`](Self::max_attempts) failed deliveries and the last error.
+    #[must_use]
+    pub fn on_dead_letter<F>(self, callback: F) -> Self
+    where
+        F: Fn(&Event, &(dyn StdError + Send + Sync)) + Send + Sync + 'static,
+    {
+        Self {
+            dead_letter: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Register a handler for the events of a type of a game.
+    #[must_use]
+    pub fn on<F, Fut, E>(self, game_id: GameId, event_type: EventType, handler: F) -> Self
+    where
+        F: Fn(Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = std::result::Result<(), E>> + Send + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        self.register(game_id, Some(event_type), handler)
+    }
+
+    /// Register a handler for all events of a game.
+    #[must_use]
+    pub fn on_any<F, Fut, E>(self, game_id: GameId, handler: F) -> Self
+    where
+        F: Fn(Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = std::result::Result<(), E>> + Send + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        self.register(game_id, None, handler)
+    }
+
+    fn register<F, Fut, E>(
+        mut self,
+        game_id: GameId,
+        event_type: Option<EventType>,
+        handler: F,
+    ) -> Self
+    where
+        F: Fn(Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = std::result::Result<(), E>> + Send + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        let handler: Handler = Arc::new(move |event| handler(event).map_err(Into::into).boxed());
+        self.handlers.push(Registration {
+            game_id,
+            event_type,
+            handler,
+        });
+        self
+    }
+
+    /// Poll the event logs on the interval and dispatch the events until a non-transient error
+    /// occurs.
+    pub async fn run(&self) -> Result<()> {
+        let mut ticker = interval(self.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match self.poll().await {
+                Ok(_) => {}
+                Err(e) if is_transient(&e) => {
+                    warn!("dispatcher: polling failed: {e}");
+                    if let Some(retry_after) = e.retry_after() {
+                        tokio::time::sleep(retry_after).await;
+                        ticker.reset();
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Poll the event logs once and dispatch the new events.
+    ///
+    /// Returns the number of events that were handled successfully.
+    pub async fn poll(&self) -> Result<usize> {
+        let mut games = self.handlers.iter().map(|r| r.game_id).collect::<Vec<_>>();
+        games.sort();
+        games.dedup();
+
+        let mut handled = 0;
+        for game_id in games {
+            handled += self.poll_game(game_id).await?;
+        }
+        Ok(handled)
+    }
+
+    async fn poll_game(&self, game_id: GameId) -> Result<usize> {
+        let mut cursor = match self.store.load(game_id).await? {
+            Some(cursor) => cursor,
+            None => {
+                let cursor = Cursor::now();
+                self.store.save(game_id, cursor).await?;
+                cursor
+            }
+        };
+        let route = Route::GetModsEvents { game_id };
+        let events = fetch::<Event>(&self.modio, route, &Filter::default(), &cursor).await?;
+
+        let mut handled = 0;
+        for event in events {
+            if !cursor.is_new(event.id) {
+                continue;
+            }
+            match self.dispatch(game_id, &event).await {
+                Ok(()) => {
+                    self.attempts().remove(&event.id);
+                    handled += 1;
+                }
+                Err(e) => {
+                    let attempts = {
+                        let mut attempts = self.attempts();
+                        let n = attempts.entry(event.id).or_default();
+                        *n += 1;
+                        *n
+                    };
+                    if self.max_attempts.map_or(true, |max| attempts < max) {
+                        warn!(
+                            "dispatcher: event {} of game {game_id} failed {attempts} time(s), \
+                             later events are blocked until it succeeds",
+                            event.id
+                        );
+                        // Stop here and deliver the event again with the next poll.
+                        break;
+                    }
+                    warn!(
+                        "dispatcher: skipping event {} of game {game_id} after {attempts} \
+                         failed attempts",
+                        event.id
+                    );
+                    self.attempts().remove(&event.id);
+                    if let Some(dead_letter) = &self.dead_letter {
+                        dead_letter(&event, &*e);
+                    }
+                }
+            }
+            cursor.advance(event.id, event.date_added);
+            self.store.save(game_id, cursor).await?;
+        }
+        Ok(handled)
+    }
+
+    /// Call the handlers of the event. Returns the error of the first failed handler.
+    async fn dispatch(&self, game_id: GameId, event: &Event) -> Result<(), BoxError> {
+        let handlers = self.handlers.iter().filter(|r| {
+            r.game_id == game_id && r.event_type.map_or(true, |t| t == event.event_type)
+        });
+        for registration in handlers {
+            debug!("dispatching event {} of game {game_id}", event.id);
+            if let Err(e) = (registration.handler)(event.clone()).await {
+                error!(
+                    "dispatcher: handler for event {} ({}) failed: {e}",
+                    event.id, event.event_type
+                );
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    fn attempts(&self) -> std::sync::MutexGuard<'_, HashMap<EventId, u32>> {
+        self.attempts.lock().expect("dispatcher attempts poisoned")
+    }
+}
@@ -0,0 +1,290 @@
+//! Long-lived streams of mod and user events.
+//!
+//! An [`EventStream`] polls an event log on an interval and yields every new event once, ordered
+//! by the event id. The position in the event log is tracked with a [`Cursor`] that can be stored
+//! and used to resume the stream later.
+//!
+//! Transient errors (connection errors, server errors and rate limits) are yielded as `Err` items
+//! and polling continues with the next interval. Any other error ends the stream.
+//!
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use futures_util::TryStreamExt;
+//! use modio::events::Cursor;
+//! use modio::filter::Filter;
+//! use modio::types::id::Id;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #     let modio = modio::Modio::new("api-key")?;
+//! let st = modio
+//!     .game(Id::new(51))
+//!     .mods()
+//!     .event_stream(Filter::default())
+//!     .interval(Duration::from_secs(10))
+//!     .resume(Cursor::now())
+//!     .into_stream();
+//! tokio::pin!(st);
+//!
+//! while let Some(event) = st.try_next().await? {
+//!     println!("{:?}", event);
+//!     // Store `Cursor::from_event(&event)` to resume the stream later.
+//! }
+//! #     Ok(())
+//! # }
+//! ```
+use std::collections::VecDeque;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures_util::stream;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tokio::time::{interval, Interval, MissedTickBehavior};
+use tracing::debug;
+
+use crate::filter::prelude::*;
+use crate::prelude::*;
+use crate::types::id::EventId;
+
+pub mod dispatch;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Position in an event log.
+///
+/// Events are requested with `id > event_id` if an event was seen before, otherwise with
+/// `date_added >= date_added` so the events added in the same second are not missed. Once the
+/// first event is seen, the events behind it are skipped by their id.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Cursor {
+    pub event_id: Option<EventId>,
+    pub date_added: u64,
+}
+
+impl Cursor {
+    /// Create a cursor positioned after the given event.
+    pub fn new(event_id: EventId, date_added: u64) -> Self {
+        Self {
+            event_id: Some(event_id),
+            date_added,
+        }
+    }
+
+    /// Create a cursor positioned after the event.
+    pub fn from_event<E: StreamEvent>(event: &E) -> Self {
+        Self::new(event.id(), event.date_added())
+    }
+
+    /// Create a cursor for the events added at or after the unix timestamp `date_added`.
+    pub fn since(date_added: u64) -> Self {
+        Self {
+            event_id: None,
+            date_added,
+        }
+    }
+
+    /// Create a cursor for the events added from now on.
+    pub fn now() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self::since(now)
+    }
+
+    pub(crate) fn filter(&self) -> Filter {
+        match self.event_id {
+            Some(id) => Id::gt(id),
+            None => DateAdded::ge(self.date_added),
+        }
+    }
+
+    /// Returns `true` if the event is not behind the cursor.
+    pub(crate) fn is_new(&self, id: EventId) -> bool {
+        self.event_id.map_or(true, |last| id > last)
+    }
+
+    pub(crate) fn advance(&mut self, id: EventId, date_added: u64) {
+        if self.is_new(id) {
+            self.event_id = Some(id);
+        }
+        self.date_added = self.date_added.max(date_added);
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for crate::types::Event {}
+    impl Sealed for crate::types::mods::Event {}
+}
+
+/// Event types that can be streamed with an [`EventStream`].
+///
+/// This trait is sealed and implemented for the user events ([`user::Event`]) and the mod events
+/// ([`mods::Event`]).
+///
+/// [`user::Event`]: crate::user::Event
+/// [`mods::Event`]: crate::mods::Event
+pub trait StreamEvent: sealed::Sealed + DeserializeOwned + Send + 'static {
+    /// Returns the id of the event.
+    fn id(&self) -> EventId;
+
+    /// Returns the unix timestamp of the event.
+    fn date_added(&self) -> u64;
+}
+
+impl StreamEvent for crate::types::Event {
+    fn id(&self) -> EventId {
+        self.id
+    }
+
+    fn date_added(&self) -> u64 {
+        self.date_added
+    }
+}
+
+impl StreamEvent for crate::types::mods::Event {
+    fn id(&self) -> EventId {
+        self.id
+    }
+
+    fn date_added(&self) -> u64 {
+        self.date_added
+    }
+}
+
+/// Builder of a long-lived event stream.
+///
+/// Created with [`Mods::event_stream`], [`ModRef::event_stream`] or [`Me::event_stream`].
+/// By default the event log is polled every 30 seconds for the events added from now on.
+///
+/// [`Mods::event_stream`]: crate::mods::Mods::event_stream
+/// [`ModRef::event_stream`]: crate::mods::ModRef::event_stream
+/// [`Me::event_stream`]: crate::user::Me::event_stream
+pub struct EventStream<T> {
+    modio: Modio,
+    route: Route,
+    filter: Filter,
+    interval: Duration,
+    cursor: Option<Cursor>,
+    phantom: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T: StreamEvent> EventStream<T> {
+    pub(crate) fn new(modio: Modio, route: Route, filter: Filter) -> Self {
+        Self {
+            modio,
+            route,
+            filter,
+            interval: DEFAULT_INTERVAL,
+            cursor: None,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Set the interval between two polls of the event log.
+    #[must_use]
+    pub fn interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    /// Resume the stream from a stored cursor.
+    #[must_use]
+    pub fn resume(self, cursor: Cursor) -> Self {
+        Self {
+            cursor: Some(cursor),
+            ..self
+        }
+    }
+
+    /// Returns the stream of events.
+    pub fn into_stream(self) -> impl Stream<Item = Result<T>> + Send {
+        let state = State {
+            modio: self.modio,
+            route: self.route,
+            filter: self.filter,
+            cursor: self.cursor.unwrap_or_else(Cursor::now),
+            interval: self.interval.max(Duration::from_millis(1)),
+            ticker: None,
+            buffer: VecDeque::new(),
+            retry_after: None,
+            done: false,
+        };
+        stream::unfold(state, |mut state| async move {
+            let item = state.next().await?;
+            Some((item, state))
+        })
+    }
+}
+
+struct State<T> {
+    modio: Modio,
+    route: Route,
+    filter: Filter,
+    cursor: Cursor,
+    interval: Duration,
+    ticker: Option<Interval>,
+    buffer: VecDeque<T>,
+    retry_after: Option<Duration>,
+    done: bool,
+}
+
+impl<T: StreamEvent> State<T> {
+    async fn next(&mut self) -> Option<Result<T>> {
+        loop {
+            if let Some(event) = self.buffer.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.done {
+                return None;
+            }
+            let period = self.interval;
+            let ticker = self.ticker.get_or_insert_with(|| {
+                let mut ticker = interval(period);
+                ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                ticker
+            });
+            if let Some(retry_after) = self.retry_after.take() {
+                tokio::time::sleep(retry_after).await;
+                ticker.reset();
+            }
+            ticker.tick().await;
+
+            match fetch::<T>(&self.modio, self.route, &self.filter, &self.cursor).await {
+                Ok(events) => {
+                    for event in events {
+                        if self.cursor.is_new(event.id()) {
+                            self.cursor.advance(event.id(), event.date_added());
+                            self.buffer.push_back(event);
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.retry_after = e.retry_after();
+                    let transient = is_transient(&e);
+                    debug!("event stream: polling failed (transient: {transient}): {e}");
+                    self.done = !transient;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Fetch the events after the cursor ordered by id.
+pub(crate) async fn fetch<T: StreamEvent>(
+    modio: &Modio,
+    route: Route,
+    filter: &Filter,
+    cursor: &Cursor,
+) -> Result<Vec<T>> {
+    let filter = filter.clone().and(cursor.filter()).order_by(Id::asc());
+    Query::new(modio.clone(), route, filter).collect().await
+}
+
+/// Returns `true` for errors that may succeed when the request is sent again.
+pub(crate) fn is_transient(e: &crate::Error) -> bool {
+    e.is_request() || e.is_ratelimited() || e.status().map_or(false, |s| s.is_server_error())
+}
@@ -1,16 +1,27 @@
+use std::io;
 use std::marker::Unpin;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Instant;
 
-use futures_util::TryFutureExt;
+use bytes::Bytes;
+use futures_util::{Stream, TryFutureExt, TryStreamExt};
 use mime::Mime;
-use reqwest::multipart::Part;
-use reqwest::Body;
 use tokio::fs::File;
 use tokio::io::AsyncRead;
 use tokio_util::io::ReaderStream;
 
+use crate::files::UploadProgress;
+use crate::multipart::Part;
+use crate::transport::Body;
+
+type ByteStream = Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send + Sync>>;
+
 pub struct FileSource {
-    pub body: Body,
+    pub stream: ByteStream,
+    pub size: Option<u64>,
+    pub path: Option<PathBuf>,
     pub filename: String,
     pub mime: Mime,
 }
@@ -18,12 +29,15 @@ pub struct FileSource {
 impl FileSource {
     pub fn new_from_file<P: AsRef<Path>>(file: P, filename: String, mime: Mime) -> Self {
         let file = file.as_ref().to_path_buf();
-        let st = File::open(file)
+        let size = std::fs::metadata(&file).ok().map(|m| m.len());
+        let st = File::open(file.clone())
             .map_ok(ReaderStream::new)
             .try_flatten_stream();
 
         FileSource {
-            body: Body::wrap_stream(st),
+            stream: Box::pin(st),
+            size,
+            path: Some(file),
             filename,
             mime,
         }
@@ -34,18 +48,56 @@ impl FileSource {
         T: AsyncRead + Send + Sync + Unpin + 'static,
     {
         FileSource {
-            body: Body::wrap_stream(ReaderStream::new(read)),
+            stream: Box::pin(ReaderStream::new(read)),
+            size: None,
+            path: None,
             filename,
             mime,
         }
     }
+
+    /// Compute the MD5 hash of the file without loading it into memory.
+    ///
+    /// Returns `None` for sources that are not backed by a file.
+    pub async fn md5(&self) -> io::Result<Option<String>> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let mut context = md5::Context::new();
+        let mut st = ReaderStream::new(File::open(path).await?);
+        while let Some(chunk) = st.try_next().await? {
+            context.consume(&chunk);
+        }
+        Ok(Some(format!("{:x}", context.compute())))
+    }
+
+    /// Invoke the callback with the [`UploadProgress`] after each chunk of the stream is read.
+    pub fn with_progress<F>(self, callback: F) -> Self
+    where
+        F: FnMut(&UploadProgress) + Send + 'static,
+    {
+        let started = Instant::now();
+        let mut progress = UploadProgress::new(self.size);
+        // The request body must be `Sync`, the callback is only required to be `Send`.
+        let mut callback = Mutex::new(callback);
+        let st = self.stream.inspect_ok(move |chunk| {
+            progress.update(chunk.len() as u64, started.elapsed());
+            let callback = callback.get_mut().expect("progress callback poisoned");
+            callback(&progress);
+        });
+
+        FileSource {
+            stream: Box::pin(st),
+            ..self
+        }
+    }
 }
 
 impl From<FileSource> for Part {
     fn from(source: FileSource) -> Part {
-        Part::stream(source.body)
+        Part::stream(Body::wrap_stream(source.stream))
             .file_name(source.filename)
-            .mime_str(source.mime.as_ref())
-            .expect("FileSource::into::<Part>()")
+            .mime(source.mime)
     }
 }
@@ -0,0 +1,531 @@
+//! Modfile interface
+use std::ffi::OsStr;
+use std::marker::Unpin;
+use std::path::Path;
+use std::time::Duration;
+
+use futures_util::TryFutureExt;
+use mime::APPLICATION_OCTET_STREAM;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use tokio::io::AsyncRead;
+
+use crate::error;
+use crate::file_source::FileSource;
+use crate::prelude::*;
+use crate::response::Response;
+use crate::types::id::{FileId, GameId, ModId};
+use crate::TargetPlatform;
+
+pub mod multipart;
+#[cfg(feature = "zip")]
+pub mod package;
+
+use multipart::{CreateUploadOptions, MultipartUpload};
+#[cfg(feature = "zip")]
+use package::Package;
+
+pub use crate::types::files::{
+    Download, File, FileHash, Platform, PlatformStatus, UploadPart, UploadSession, UploadStatus,
+    VirusResult, VirusScan, VirusStatus,
+};
+
+/// Interface for the modfiles of a mod.
+#[derive(Clone)]
+pub struct Files {
+    modio: Modio,
+    game: GameId,
+    mod_id: ModId,
+}
+
+impl Files {
+    pub(crate) fn new(modio: Modio, game: GameId, mod_id: ModId) -> Self {
+        Self {
+            modio,
+            game,
+            mod_id,
+        }
+    }
+
+    /// Returns a `Query` interface to retrieve all files that are published
+    /// for a mod this `Files` refers to.
+    ///
+    /// See [Filters and sorting](filters).
+    pub fn search(&self, filter: Filter) -> Query<File> {
+        let route = Route::GetFiles {
+            game_id: self.game,
+            mod_id: self.mod_id,
+        };
+        Query::new(self.modio.clone(), route, filter)
+    }
+
+    /// Return a reference to a file.
+    pub fn get(&self, id: FileId) -> FileRef {
+        FileRef::new(self.modio.clone(), self.game, self.mod_id, id)
+    }
+
+    /// Add a file for a mod that this `Files` refers to. [required: token]
+    ///
+    /// The MD5 hash of the file is computed before the upload if enabled with
+    /// [`AddFileOptions::compute_filehash`].
+    #[allow(clippy::should_implement_trait)]
+    pub async fn add(self, mut options: AddFileOptions) -> Result<File> {
+        let route = Route::AddFile {
+            game_id: self.game,
+            mod_id: self.mod_id,
+        };
+        #[cfg(feature = "zip")]
+        let _archive = match options.source {
+            Source::Package(package, progress) => {
+                let (source, archive) = package_source(package, progress).await?;
+                options.source = Source::File(source);
+                options.filehash.get_or_insert_with(|| archive.md5.clone());
+                Some(archive)
+            }
+            source => {
+                options.source = source;
+                None
+            }
+        };
+        if let (true, None, Source::File(source)) =
+            (options.compute_filehash, &options.filehash, &options.source)
+        {
+            options.filehash = source.md5().map_err(error::io).await?;
+        }
+        self.modio
+            .request(route)
+            .multipart(options.into_form()?)
+            .send()
+            .await
+    }
+
+    /// Create a new multipart upload session for a mod that this `Files` refers to.
+    /// [required: token]
+    ///
+    /// See [`MultipartUpload`] for uploading the parts of a file.
+    pub async fn create_multipart_upload(
+        &self,
+        options: CreateUploadOptions,
+    ) -> Result<MultipartUpload> {
+        let route = Route::CreateMultipartUploadSession {
+            game_id: self.game,
+            mod_id: self.mod_id,
+        };
+        let session: UploadSession = self.modio.request(route).form(&options).send().await?;
+        Ok(self.multipart_upload(session.upload_id))
+    }
+
+    /// Return a reference to an existing multipart upload session.
+    pub fn multipart_upload<S: Into<String>>(&self, upload_id: S) -> MultipartUpload {
+        MultipartUpload::new(self.modio.clone(), self.game, self.mod_id, upload_id.into())
+    }
+
+    /// Returns a `Query` interface to retrieve the multipart upload sessions of the mod
+    /// this `Files` refers to. [required: token]
+    pub fn multipart_uploads(&self, filter: Filter) -> Query<UploadSession> {
+        let route = Route::GetMultipartUploadSessions {
+            game_id: self.game,
+            mod_id: self.mod_id,
+        };
+        Query::new(self.modio.clone(), route, filter)
+    }
+}
+
+#[cfg(feature = "zip")]
+async fn package_source(
+    package: Package,
+    progress: Option<ProgressFn>,
+) -> Result<(FileSource, package::Archive)> {
+    let filename = package.archive_name();
+    let archive = tokio::task::spawn_blocking(move || package.build())
+        .await
+        .map_err(error::archive)??;
+
+    let mut source = FileSource::new_from_file(archive.path(), filename, APPLICATION_OCTET_STREAM);
+    source.size = Some(archive.size);
+    if let Some(progress) = progress {
+        source = source.with_progress(progress);
+    }
+    Ok((source, archive))
+}
+
+/// Reference interface of a modfile.
+#[derive(Clone)]
+pub struct FileRef {
+    modio: Modio,
+    game: GameId,
+    mod_id: ModId,
+    id: FileId,
+}
+
+impl FileRef {
+    pub(crate) fn new(modio: Modio, game: GameId, mod_id: ModId, id: FileId) -> Self {
+        Self {
+            modio,
+            game,
+            mod_id,
+            id,
+        }
+    }
+
+    /// Get a reference to the Modio modfile object that this `FileRef` refers to.
+    pub async fn get(self) -> Result<File> {
+        let route = Route::GetFile {
+            game_id: self.game,
+            mod_id: self.mod_id,
+            file_id: self.id,
+        };
+        self.modio.request(route).send().await
+    }
+
+    /// Get the Modio modfile object together with the metadata of the response.
+    pub async fn get_with_meta(self) -> Result<Response<File>> {
+        let route = Route::GetFile {
+            game_id: self.game,
+            mod_id: self.mod_id,
+            file_id: self.id,
+        };
+        self.modio.request(route).send_with_meta().await
+    }
+
+    /// Edit details of a modfile. [required: token]
+    pub async fn edit(self, options: EditFileOptions) -> Result<Editing<File>> {
+        let route = Route::EditFile {
+            game_id: self.game,
+            mod_id: self.mod_id,
+            file_id: self.id,
+        };
+        self.modio.request(route).form(&options).send().await
+    }
+
+    /// Delete a modfile. [required: token]
+    pub async fn delete(self) -> Result<()> {
+        let route = Route::DeleteFile {
+            game_id: self.game,
+            mod_id: self.mod_id,
+            file_id: self.id,
+        };
+        self.modio.request(route).send().await
+    }
+
+    /// Edit the platform status of a modfile. [required: token]
+    pub async fn edit_platform_status(self, options: EditPlatformStatusOptions) -> Result<File> {
+        let route = Route::ManagePlatformStatus {
+            game_id: self.game,
+            mod_id: self.mod_id,
+            file_id: self.id,
+        };
+        self.modio.request(route).form(&options).send().await
+    }
+}
+
+/// Modfile filters and sorting.
+///
+/// # Filters
+/// - `Fulltext`
+/// - `Id`
+/// - `ModId`
+/// - `DateAdded`
+/// - `DateScanned`
+/// - `VirusStatus`
+/// - `VirusPositive`
+/// - `Filesize`
+/// - `Filehash`
+/// - `Filename`
+/// - `Version`
+/// - `Changelog`
+///
+/// # Sorting
+/// - `Id`
+/// - `ModId`
+/// - `DateAdded`
+/// - `Version`
+///
+/// See [modio docs](https://docs.mod.io/#get-modfiles) for more information.
+///
+/// By default this returns up to `100` items. You can limit the result by using `limit` and
+/// `offset`.
+///
+/// # Example
+/// ```
+/// use modio::filter::prelude::*;
+/// use modio::files::filters::Id;
+///
+/// let filter = Id::_in(vec![1, 2]).order_by(Id::desc());
+/// ```
+#[rustfmt::skip]
+pub mod filters {
+    #[doc(inline)]
+    pub use crate::filter::prelude::Fulltext;
+    #[doc(inline)]
+    pub use crate::filter::prelude::Id;
+    #[doc(inline)]
+    pub use crate::filter::prelude::ModId;
+    #[doc(inline)]
+    pub use crate::filter::prelude::DateAdded;
+
+    filter!(DateScanned, DATE_SCANNED, "date_scanned", Eq, NotEq, In, Cmp);
+    filter!(VirusStatus, VIRUS_STATUS, "virus_status", Eq, NotEq, In, Cmp);
+    filter!(VirusPositive, VIRUS_POSITIVE, "virus_positive", Eq, NotEq, In, Cmp);
+    filter!(Filesize, FILESIZE, "filesize", Eq, NotEq, In, Cmp, OrderBy);
+    filter!(Filehash, FILEHASH, "filehash", Eq, NotEq, In, Like);
+    filter!(Filename, FILENAME, "filename", Eq, NotEq, In, Like);
+    filter!(Version, VERSION, "version", Eq, NotEq, In, Like, OrderBy);
+    filter!(Changelog, CHANGELOG, "changelog", Eq, NotEq, In, Like);
+}
+
+/// Progress of a file upload passed to the callbacks of [`AddFileOptions::on_progress`] and
+/// [`MultipartUpload::on_progress`].
+#[derive(Clone, Debug)]
+pub struct UploadProgress {
+    sent: u64,
+    total: Option<u64>,
+    elapsed: Duration,
+}
+
+impl UploadProgress {
+    pub(crate) fn new(total: Option<u64>) -> Self {
+        Self {
+            sent: 0,
+            total,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub(crate) fn update(&mut self, len: u64, elapsed: Duration) {
+        self.sent += len;
+        self.elapsed = elapsed;
+    }
+
+    /// Number of bytes sent so far.
+    pub fn sent(&self) -> u64 {
+        self.sent
+    }
+
+    /// Total size of the upload if known.
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Time elapsed since the upload started.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+#[cfg(feature = "zip")]
+type ProgressFn = Box<dyn FnMut(&UploadProgress) + Send>;
+
+enum Source {
+    File(FileSource),
+    #[cfg(feature = "zip")]
+    Package(Package, Option<ProgressFn>),
+    Upload(String),
+}
+
+pub struct AddFileOptions {
+    source: Source,
+    version: Option<String>,
+    changelog: Option<String>,
+    active: Option<bool>,
+    filehash: Option<String>,
+    compute_filehash: bool,
+    metadata_blob: Option<String>,
+}
+
+impl AddFileOptions {
+    pub fn with_read<R, S>(inner: R, filename: S) -> AddFileOptions
+    where
+        R: AsyncRead + Send + Sync + Unpin + 'static,
+        S: Into<String>,
+    {
+        AddFileOptions {
+            source: Source::File(FileSource::new_from_read(
+                inner,
+                filename.into(),
+                APPLICATION_OCTET_STREAM,
+            )),
+            version: None,
+            changelog: None,
+            active: None,
+            filehash: None,
+            compute_filehash: false,
+            metadata_blob: None,
+        }
+    }
+
+    pub fn with_file<P: AsRef<Path>>(file: P) -> AddFileOptions {
+        let file = file.as_ref();
+        let filename = file
+            .file_name()
+            .and_then(OsStr::to_str)
+            .map_or_else(String::new, ToString::to_string);
+
+        Self::with_file_name(file, filename)
+    }
+
+    pub fn with_file_name<P, S>(file: P, filename: S) -> AddFileOptions
+    where
+        P: AsRef<Path>,
+        S: Into<String>,
+    {
+        let file = file.as_ref();
+
+        AddFileOptions {
+            source: Source::File(FileSource::new_from_file(
+                file,
+                filename.into(),
+                APPLICATION_OCTET_STREAM,
+            )),
+            version: None,
+            changelog: None,
+            active: None,
+            filehash: None,
+            compute_filehash: false,
+            metadata_blob: None,
+        }
+    }
+
+    /// Add the file of a completed multipart upload session.
+    ///
+    /// See [`MultipartUpload`].
+    pub fn with_upload_session<S: Into<String>>(upload_id: S) -> AddFileOptions {
+        AddFileOptions {
+            source: Source::Upload(upload_id.into()),
+            version: None,
+            changelog: None,
+            active: None,
+            filehash: None,
+            compute_filehash: false,
+            metadata_blob: None,
+        }
+    }
+
+    /// Pack a directory into a zip archive and upload the archive.
+    ///
+    /// The archive is written to a temporary file when the options are passed to
+    /// [`Files::add`]. The size and the MD5 hash of the archive are computed and the hash is sent
+    /// as `filehash` unless set explicitly.
+    #[cfg(feature = "zip")]
+    pub fn with_package(package: Package) -> AddFileOptions {
+        AddFileOptions {
+            source: Source::Package(package, None),
+            version: None,
+            changelog: None,
+            active: None,
+            filehash: None,
+            compute_filehash: false,
+            metadata_blob: None,
+        }
+    }
+
+    /// Pack all files of a directory into a zip archive and upload the archive.
+    ///
+    /// See [`AddFileOptions::with_package`] and [`Package`] for selecting the files with glob
+    /// patterns.
+    #[cfg(feature = "zip")]
+    pub fn with_directory<P: AsRef<Path>>(dir: P) -> AddFileOptions {
+        Self::with_package(Package::new(dir))
+    }
+
+    /// Set a callback that is invoked with the progress of the upload.
+    ///
+    /// The callback has no effect for files of multipart upload sessions.
+    #[must_use]
+    pub fn on_progress<F>(self, callback: F) -> Self
+    where
+        F: FnMut(&UploadProgress) + Send + 'static,
+    {
+        let source = match self.source {
+            Source::File(source) => Source::File(source.with_progress(callback)),
+            #[cfg(feature = "zip")]
+            Source::Package(package, _) => Source::Package(package, Some(Box::new(callback))),
+            source @ Source::Upload(_) => source,
+        };
+        Self { source, ..self }
+    }
+
+    option!(version);
+    option!(changelog);
+    option!(active: bool);
+    option!(filehash);
+    option!(metadata_blob);
+
+    /// Compute the MD5 hash of the file and send it as `filehash` so the server can verify the
+    /// integrity of the upload.
+    ///
+    /// The file is read in chunks before the upload starts. The option is ignored for sources
+    /// created with [`AddFileOptions::with_read`] and if a `filehash` is set explicitly.
+    #[must_use]
+    pub fn compute_filehash(self) -> Self {
+        Self {
+            compute_filehash: true,
+            ..self
+        }
+    }
+
+    fn into_form(self) -> Result<Form> {
+        let mut form = Form::new();
+        if let Some(version) = self.version {
+            form = form.text("version", version);
+        }
+        if let Some(changelog) = self.changelog {
+            form = form.text("changelog", changelog);
+        }
+        if let Some(active) = self.active {
+            form = form.text("active", active.to_string());
+        }
+        if let Some(filehash) = self.filehash {
+            form = form.text("filehash", filehash);
+        }
+        if let Some(metadata_blob) = self.metadata_blob {
+            form = form.text("metadata_blob", metadata_blob);
+        }
+        match self.source {
+            Source::File(source) => Ok(form.part("filedata", source.into())),
+            #[cfg(feature = "zip")]
+            Source::Package(..) => Err(error::builder("the package has not been built")),
+            Source::Upload(upload_id) => Ok(form.text("upload_id", upload_id)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct EditFileOptions {
+    params: std::collections::BTreeMap<&'static str, String>,
+}
+
+impl EditFileOptions {
+    option!(version >> "version");
+    option!(changelog >> "changelog");
+    option!(active: bool >> "active");
+    option!(metadata_blob >> "metadata_blob");
+}
+
+impl_serialize_params!(EditFileOptions >> params);
+
+pub struct EditPlatformStatusOptions {
+    approved: Vec<TargetPlatform>,
+    denied: Vec<TargetPlatform>,
+}
+
+impl EditPlatformStatusOptions {
+    pub fn new(approved: &[TargetPlatform], denied: &[TargetPlatform]) -> Self {
+        Self {
+            approved: approved.to_vec(),
+            denied: denied.to_vec(),
+        }
+    }
+}
+
+impl Serialize for EditPlatformStatusOptions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_map(Some(self.approved.len() + self.denied.len()))?;
+        for target in &self.approved {
+            s.serialize_entry("approved[]", target)?;
+        }
+        for target in &self.denied {
+            s.serialize_entry("denied[]", target)?;
+        }
+        s.end()
+    }
+}
@@ -0,0 +1,234 @@
+//! Multipart upload sessions
+//!
+//! Large files can be uploaded in parts of up to 50 MiB. A session is created with
+//! [`Files::create_multipart_upload`], the parts are uploaded with [`MultipartUpload::add_part`]
+//! and the session is finalized with [`MultipartUpload::complete`]. The completed upload is then
+//! added to the mod with [`AddFileOptions::with_upload_session`].
+//!
+//! An interrupted upload can be resumed with [`Files::multipart_upload`] and the `upload_id` of
+//! the session, [`MultipartUpload::upload_file`] skips the parts that were already uploaded.
+//!
+//! # Example
+//! ```no_run
+//! # use modio::types::id::Id;
+//! use modio::files::multipart::CreateUploadOptions;
+//! use modio::files::AddFileOptions;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #     let modio = modio::Modio::new("api-key")?;
+//! let files = modio.game(Id::new(1)).mod_(Id::new(2)).files();
+//!
+//! let upload = files
+//!     .create_multipart_upload(CreateUploadOptions::new("modfile.zip"))
+//!     .await?
+//!     .on_progress(|p| println!("{}/{:?}", p.sent(), p.total()));
+//!
+//! let session = upload.upload_file("modfile.zip").await?;
+//!
+//! let options = AddFileOptions::with_upload_session(session.upload_id).version("1.0");
+//! files.add(options).await?;
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [`Files::create_multipart_upload`]: super::Files::create_multipart_upload
+//! [`Files::multipart_upload`]: super::Files::multipart_upload
+//! [`AddFileOptions::with_upload_session`]: super::AddFileOptions::with_upload_session
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures_util::TryFutureExt;
+use http::header::{HeaderValue, CONTENT_RANGE, CONTENT_TYPE};
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, SeekFrom};
+
+use crate::error;
+use crate::filter::{custom_filter, Operator};
+use crate::prelude::*;
+use crate::types::files::{UploadPart, UploadSession};
+use crate::types::id::{GameId, ModId};
+
+use super::UploadProgress;
+
+/// Maximum size of an upload part.
+pub const PART_SIZE: u64 = 50 * 1024 * 1024;
+
+type ProgressFn = Box<dyn FnMut(&UploadProgress) + Send>;
+
+/// Reference interface of a multipart upload session.
+pub struct MultipartUpload {
+    modio: Modio,
+    game: GameId,
+    mod_id: ModId,
+    upload_id: String,
+    progress: Option<ProgressFn>,
+}
+
+impl MultipartUpload {
+    pub(crate) fn new(modio: Modio, game: GameId, mod_id: ModId, upload_id: String) -> Self {
+        Self {
+            modio,
+            game,
+            mod_id,
+            upload_id,
+            progress: None,
+        }
+    }
+
+    /// Returns the id of the upload session.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Set a callback that is invoked with the progress of [`MultipartUpload::upload_file`].
+    #[must_use]
+    pub fn on_progress<F>(self, callback: F) -> Self
+    where
+        F: FnMut(&UploadProgress) + Send + 'static,
+    {
+        Self {
+            progress: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Returns a `Query` interface to retrieve the uploaded parts of the session.
+    /// [required: token]
+    pub fn parts(&self) -> Query<UploadPart> {
+        let route = Route::GetMultipartUploadParts {
+            game_id: self.game,
+            mod_id: self.mod_id,
+        };
+        let filter = custom_filter("upload_id", Operator::Equals, self.upload_id.as_str());
+        Query::new(self.modio.clone(), route, filter)
+    }
+
+    /// Upload a part of the file starting at byte offset `start`. [required: token]
+    ///
+    /// `total` is the size of the complete file. Every part except the last one must have the
+    /// size of [`PART_SIZE`].
+    pub async fn add_part(&self, start: u64, total: u64, data: Bytes) -> Result<UploadPart> {
+        let route = Route::AddMultipartUploadPart {
+            game_id: self.game,
+            mod_id: self.mod_id,
+        };
+        let end = (start + data.len() as u64).saturating_sub(1);
+        let range = format!("bytes {start}-{end}/{total}");
+        let range = HeaderValue::from_str(&range).map_err(error::builder)?;
+
+        self.modio
+            .request(route)
+            .query(&[("upload_id", &self.upload_id)])
+            .header(CONTENT_RANGE, range)
+            .header(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/octet-stream"),
+            )
+            .body(data)
+            .send()
+            .await
+    }
+
+    /// Complete the upload session after all parts are uploaded. [required: token]
+    pub async fn complete(&self) -> Result<UploadSession> {
+        let route = Route::CompleteMultipartUploadSession {
+            game_id: self.game,
+            mod_id: self.mod_id,
+        };
+        self.modio
+            .request(route)
+            .query(&[("upload_id", &self.upload_id)])
+            .send()
+            .await
+    }
+
+    /// Cancel the upload session and delete the uploaded parts. [required: token]
+    pub async fn abort(self) -> Result<()> {
+        let route = Route::DeleteMultipartUploadSession {
+            game_id: self.game,
+            mod_id: self.mod_id,
+        };
+        self.modio
+            .request(route)
+            .query(&[("upload_id", &self.upload_id)])
+            .send()
+            .await
+    }
+
+    /// Upload the file in parts of [`PART_SIZE`] and complete the session. [required: token]
+    ///
+    /// Parts that were already uploaded to the session are skipped which allows to resume an
+    /// interrupted upload. The skipped parts are not reported to the progress callback, they are
+    /// included in [`UploadProgress::sent`] of the first reported part.
+    pub async fn upload_file<P: AsRef<Path>>(mut self, file: P) -> Result<UploadSession> {
+        let mut file = File::open(file).map_err(error::io).await?;
+        let total = file.metadata().map_err(error::io).await?.len();
+
+        let uploaded = self
+            .parts()
+            .collect()
+            .await?
+            .into_iter()
+            .map(|p| p.part_number)
+            .collect::<HashSet<_>>();
+
+        // The part number, the start and the length of each part.
+        let mut parts = Vec::new();
+        let mut start = 0;
+        let mut part_number = 1;
+        while start < total {
+            let len = PART_SIZE.min(total - start);
+            parts.push((part_number, start, len));
+            start += len;
+            part_number += 1;
+        }
+
+        let started = Instant::now();
+        let mut progress = UploadProgress::new(Some(total));
+        let skipped = parts
+            .iter()
+            .filter(|(part_number, ..)| uploaded.contains(part_number))
+            .map(|(.., len)| len)
+            .sum();
+        progress.update(skipped, Duration::ZERO);
+
+        for (part_number, start, len) in parts {
+            if uploaded.contains(&part_number) {
+                continue;
+            }
+            let mut buf = vec![0; len as usize];
+            file.seek(SeekFrom::Start(start)).map_err(error::io).await?;
+            file.read_exact(&mut buf).map_err(error::io).await?;
+            self.add_part(start, total, Bytes::from(buf)).await?;
+
+            progress.update(len, started.elapsed());
+            if let Some(callback) = &mut self.progress {
+                callback(&progress);
+            }
+        }
+        self.complete().await
+    }
+}
+
+/// Options for creating a multipart upload session.
+pub struct CreateUploadOptions {
+    params: BTreeMap<&'static str, String>,
+}
+
+impl CreateUploadOptions {
+    pub fn new<S: Into<String>>(filename: S) -> CreateUploadOptions {
+        let mut params = BTreeMap::new();
+        params.insert("filename", filename.into());
+        CreateUploadOptions { params }
+    }
+
+    option!(
+        /// An optional nonce to prevent the creation of duplicate upload sessions.
+        nonce >> "nonce"
+    );
+}
+
+impl_serialize_params!(CreateUploadOptions >> params);
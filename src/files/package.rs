@@ -0,0 +1,232 @@
+//! Packaging of mod directories into zip archives
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use tempfile::NamedTempFile;
+use walkdir::WalkDir;
+use zip::write::{FileOptions, ZipWriter};
+use zip::CompressionMethod;
+
+use crate::error::{self, Result};
+
+/// A directory that is packed into a zip archive before it is uploaded.
+///
+/// Files are selected relative to the directory. If no include patterns are given, all files are
+/// included. Exclude patterns take precedence over include patterns.
+///
+/// # Example
+/// ```no_run
+/// # use modio::types::id::Id;
+/// use modio::files::package::Package;
+/// use modio::files::AddFileOptions;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #     let modio = modio::Modio::new("api-key")?;
+/// let package = Package::new("build/my-mod")
+///     .include("**/*.pak")
+///     .include("README.md")
+///     .exclude("**/*.tmp");
+///
+/// let options = AddFileOptions::with_package(package).version("1.2.0");
+/// let file = modio.game(Id::new(1)).mod_(Id::new(2)).files().add(options).await?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Package {
+    dir: PathBuf,
+    filename: Option<String>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl Package {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+            filename: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+
+    /// Set the filename of the archive.
+    ///
+    /// Defaults to the name of the directory with a `.zip` extension.
+    #[must_use]
+    pub fn filename<S: Into<String>>(self, filename: S) -> Self {
+        Self {
+            filename: Some(filename.into()),
+            ..self
+        }
+    }
+
+    /// Add a glob pattern of files to include.
+    #[must_use]
+    pub fn include<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.include.push(pattern.into());
+        self
+    }
+
+    /// Add a glob pattern of files to exclude.
+    #[must_use]
+    pub fn exclude<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.exclude.push(pattern.into());
+        self
+    }
+
+    pub(crate) fn archive_name(&self) -> String {
+        self.filename.clone().unwrap_or_else(|| {
+            let name = self
+                .dir
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("modfile");
+            format!("{name}.zip")
+        })
+    }
+
+    /// Write the zip archive to a temporary file.
+    ///
+    /// This is a blocking operation.
+    pub(crate) fn build(&self) -> Result<Archive> {
+        let include = glob_set(&self.include)?;
+        let exclude = glob_set(&self.exclude)?;
+
+        let file = NamedTempFile::new().map_err(error::io)?;
+        let mut zip = ZipWriter::new(BufWriter::new(file));
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let entries = WalkDir::new(&self.dir).sort_by_file_name();
+        for entry in entries {
+            let entry = entry.map_err(error::io)?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let path = entry
+                .path()
+                .strip_prefix(&self.dir)
+                .expect("walkdir entry is not a child of the directory");
+            if !self.include.is_empty() && !include.is_match(path) || exclude.is_match(path) {
+                continue;
+            }
+            let name = path
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            let mut input = File::open(entry.path()).map_err(error::io)?;
+            let len = input.metadata().map_err(error::io)?.len();
+            // Entries of 4 GiB and more require the zip64 extensions.
+            let options = options.large_file(len >= u64::from(u32::MAX));
+            zip.start_file(name, options).map_err(error::archive)?;
+            io::copy(&mut input, &mut zip).map_err(error::io)?;
+        }
+
+        let mut out = zip.finish().map_err(error::archive)?;
+        out.flush().map_err(error::io)?;
+        let mut file = out.into_inner().map_err(error::io)?;
+
+        file.rewind().map_err(error::io)?;
+        let mut reader = BufReader::new(file.as_file());
+        let mut context = md5::Context::new();
+        let mut buf = [0; 8 * 1024];
+        let mut size = 0;
+        loop {
+            let n = reader.read(&mut buf).map_err(error::io)?;
+            if n == 0 {
+                break;
+            }
+            size += n as u64;
+            context.consume(&buf[..n]);
+        }
+
+        Ok(Archive {
+            file,
+            size,
+            md5: format!("{:x}", context.compute()),
+        })
+    }
+}
+
+/// Temporary zip archive of a [`Package`]. The file is deleted when dropped.
+pub(crate) struct Archive {
+    file: NamedTempFile,
+    pub(crate) size: u64,
+    pub(crate) md5: String,
+}
+
+impl Archive {
+    pub(crate) fn path(&self) -> &Path {
+        self.file.path()
+    }
+}
+
+fn glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern).map_err(error::builder)?);
+    }
+    builder.build().map_err(error::builder)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::Read;
+
+    use zip::ZipArchive;
+
+    use super::Package;
+
+    #[test]
+    fn include_and_exclude() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        fs::create_dir_all(dir.join("data/nested")).unwrap();
+        fs::write(dir.join("data/a.pak"), b"a").unwrap();
+        fs::write(dir.join("data/nested/b.pak"), b"b").unwrap();
+        fs::write(dir.join("data/cache.tmp"), b"tmp").unwrap();
+        fs::write(dir.join("data/nested/c.tmp.pak"), b"c").unwrap();
+        fs::write(dir.join("notes.txt"), b"notes").unwrap();
+        fs::write(dir.join("README.md"), b"readme").unwrap();
+
+        let package = Package::new(dir)
+            .include("**/*.pak")
+            .include("README.md")
+            .exclude("**/*.tmp*");
+        let archive = package.build().unwrap();
+
+        let content = fs::read(archive.path()).unwrap();
+        assert_eq!(archive.size, content.len() as u64);
+        assert_eq!(archive.md5, format!("{:x}", md5::compute(&content)));
+
+        let mut zip = ZipArchive::new(fs::File::open(archive.path()).unwrap()).unwrap();
+        let mut names = zip.file_names().map(String::from).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["README.md", "data/a.pak", "data/nested/b.pak"]);
+
+        let mut file = zip.by_name("data/nested/b.pak").unwrap();
+        let mut buf = String::new();
+        file.read_to_string(&mut buf).unwrap();
+        assert_eq!(buf, "b");
+    }
+
+    #[test]
+    fn missing_directory() {
+        let tmp = tempfile::tempdir().unwrap();
+        let package = Package::new(tmp.path().join("missing"));
+        assert!(package.build().err().unwrap().is_io());
+    }
+
+    #[test]
+    fn archive_name() {
+        assert_eq!(Package::new("build/my-mod").archive_name(), "my-mod.zip");
+        let package = Package::new("build/my-mod").filename("mod.zip");
+        assert_eq!(package.archive_name(), "mod.zip");
+    }
+}
@@ -409,10 +409,16 @@ impl Filter {
         }
     }
 
+    /// Combine two filters.
+    ///
+    /// Entries of `other` with the same field and operator replace the entries of `self`.
+    /// The sorting, limit and offset of `other` take precedence if they are set.
     #[must_use]
-    pub fn and(self, mut other: Filter) -> Filter {
+    pub fn and(self, other: Filter) -> Filter {
         let Filter { mut filters, .. } = self;
-        filters.append(&mut other.filters);
+        for entry in other.filters {
+            filters.replace(entry);
+        }
         Filter {
             filters,
             order_by: other.order_by.or(self.order_by),
@@ -708,6 +714,23 @@ mod test {
         assert_eq!(f.to_string(), r#"{"_sort":"foo"}"#);
     }
 
+    #[test]
+    fn and_overrides_entries() {
+        use super::prelude::*;
+
+        let f = Id::eq(1).and(Id::eq(2));
+        assert_eq!(f.to_string(), r#"{"id":"2"}"#);
+
+        let f = Id::_in(vec![1, 2]).and(Id::eq(3)).and(Id::_in(vec![4]));
+        assert_eq!(f.to_string(), r#"{"id":"3","id-in":"4"}"#);
+
+        let f = Id::eq(1).limit(10).and(NameId::eq("foo").offset(5));
+        assert_eq!(
+            f.to_string(),
+            r#"{"id":"1","name_id":"foo","_limit":10,"_offset":5}"#
+        );
+    }
+
     #[test]
     fn std_ops_add() {
         use super::prelude::*;
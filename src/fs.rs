@@ -0,0 +1,99 @@
+//! Loading and saving of local state files.
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+
+use crate::error::{self, Result};
+
+/// Read a file. A missing file returns `None`.
+pub(crate) async fn read(path: &Path) -> Result<Option<Vec<u8>>> {
+    match tokio::fs::read(path).await {
+        Ok(data) => Ok(Some(data)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(error::io(e)),
+    }
+}
+
+/// Read and deserialize a JSON file. A missing file returns `None`.
+pub(crate) async fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    match read(path).await? {
+        Some(data) => serde_json::from_slice(&data).map_err(error::decode),
+        None => Ok(None),
+    }
+}
+
+/// Write the data to a temporary file that replaces the file, an interrupted write keeps the
+/// previous content of the file.
+///
+/// With `private` the file is only readable and writable by its owner on Unix.
+pub(crate) async fn write_atomic(path: &Path, data: &[u8], private: bool) -> Result<()> {
+    let tmp = tmp_path(path);
+    write(&tmp, data, private).await.map_err(error::io)?;
+    tokio::fs::rename(&tmp, path).await.map_err(error::io)
+}
+
+/// Serialize the value as JSON and write it with [`write_atomic`].
+pub(crate) async fn write_json_atomic<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let data = serde_json::to_vec_pretty(value).map_err(error::decode)?;
+    write_atomic(path, &data, false).await
+}
+
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+async fn write(path: &Path, data: &[u8], private: bool) -> io::Result<()> {
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if private {
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path).await?;
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::PermissionsExt;
+        // The mode is only applied to new files.
+        let permissions = std::fs::Permissions::from_mode(0o600);
+        file.set_permissions(permissions).await?;
+    }
+    #[cfg(not(unix))]
+    let _ = private;
+    file.write_all(data).await?;
+    file.sync_all().await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{read_json, tmp_path, write_json_atomic};
+
+    #[tokio::test]
+    async fn json_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+
+        let state = read_json::<BTreeMap<String, u32>>(&path).await.unwrap();
+        assert!(state.is_none());
+
+        let state = BTreeMap::from([("foo".to_owned(), 1)]);
+        write_json_atomic(&path, &state).await.unwrap();
+        assert_eq!(read_json(&path).await.unwrap(), Some(state));
+        assert!(!tmp_path(&path).exists());
+
+        let err = read_json::<BTreeMap<String, u32>>(dir.path()).await;
+        assert!(err.unwrap_err().is_io());
+
+        std::fs::write(&path, b"{").unwrap();
+        let err = read_json::<BTreeMap<String, u32>>(&path).await;
+        assert!(err.unwrap_err().is_decode());
+    }
+}
@@ -7,6 +7,7 @@ use mime::IMAGE_STAR;
 use crate::file_source::FileSource;
 use crate::mods::{ModRef, Mods};
 use crate::prelude::*;
+use crate::response::Response;
 use crate::types::id::{GameId, ModId};
 
 pub use crate::types::games::{
@@ -65,6 +66,15 @@ impl GameRef {
         self.modio.request(route).send().await
     }
 
+    /// Get the Modio game object together with the metadata of the response.
+    pub async fn get_with_meta(self) -> Result<Response<Game>> {
+        let route = Route::GetGame {
+            id: self.id,
+            show_hidden_tags: None,
+        };
+        self.modio.request(route).send_with_meta().await
+    }
+
     /// Return a reference to a mod of a game.
     pub fn mod_(&self, mod_id: ModId) -> ModRef {
         ModRef::new(self.modio.clone(), self.id, mod_id)
@@ -160,7 +170,7 @@ impl Tags {
         self.modio
             .request(route)
             .form(&[("from", from), ("to", to)])
-            .send()
+            .send::<()>()
             .await?;
 
         Ok(())
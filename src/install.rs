@@ -0,0 +1,259 @@
+//! Local installation of subscribed mods.
+//!
+//! The [`Installer`] keeps a mods directory in sync with the subscriptions of the authenticated
+//! user. The primary modfile of every subscribed mod is downloaded, verified and extracted into a
+//! directory named after the mod id. The installed files are tracked in a state file in the mods
+//! directory so that only changed mods are downloaded again.
+//!
+//! # Example
+//! ```no_run
+//! use modio::install::Installer;
+//! use modio::types::id::Id;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #     let modio = modio::Modio::new(("api-key", "token"))?;
+//! let installer = Installer::new(modio, Id::new(51), "mods");
+//! let report = installer.sync().await?;
+//!
+//! println!("installed: {:?}", report.installed);
+//! println!("updated: {:?}", report.updated);
+//! println!("removed: {:?}", report.removed);
+//! for (mod_id, e) in &report.failed {
+//!     eprintln!("failed to sync mod {mod_id}: {e}");
+//! }
+//! #     Ok(())
+//! # }
+//! ```
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use futures_util::TryFutureExt;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::download::DownloadAction;
+use crate::error::{self, Error, Result};
+use crate::filter::Eq;
+use crate::fs;
+use crate::mods::filters::GameId as GameIdFilter;
+use crate::prelude::*;
+use crate::types::files::File;
+use crate::types::id::{FileId, GameId, ModId};
+
+const STATE_FILE: &str = ".modio-state.json";
+
+/// Installs the subscribed mods of a game into a local directory.
+///
+/// See the [module documentation](self) for an example.
+pub struct Installer {
+    modio: Modio,
+    game_id: GameId,
+    dir: PathBuf,
+}
+
+/// Installed mods tracked by the [`Installer`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct State {
+    mods: BTreeMap<ModId, InstalledMod>,
+}
+
+impl State {
+    /// Returns the installed modfile of a mod.
+    pub fn get(&self, mod_id: ModId) -> Option<&InstalledMod> {
+        self.mods.get(&mod_id)
+    }
+
+    /// Returns an iterator over all installed mods.
+    pub fn iter(&self) -> impl Iterator<Item = &InstalledMod> {
+        self.mods.values()
+    }
+}
+
+/// A mod installed by the [`Installer`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct InstalledMod {
+    pub mod_id: ModId,
+    pub file_id: FileId,
+    pub version: Option<String>,
+    pub filehash: String,
+    /// Directory of the extracted files relative to the mods directory.
+    pub path: PathBuf,
+}
+
+/// Changes made by [`Installer::sync`].
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct SyncReport {
+    /// Newly installed mods.
+    pub installed: Vec<ModId>,
+    /// Mods updated to a new modfile.
+    pub updated: Vec<ModId>,
+    /// Uninstalled mods that are no longer subscribed.
+    pub removed: Vec<ModId>,
+    /// Mods that failed to install, update or uninstall.
+    pub failed: Vec<(ModId, Error)>,
+}
+
+impl Installer {
+    /// Create a new installer for the mods of `game_id` in `dir`.
+    pub fn new<P: AsRef<Path>>(modio: Modio, game_id: GameId, dir: P) -> Self {
+        Self {
+            modio,
+            game_id,
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the directory of an installed mod.
+    pub fn mod_dir(&self, mod_id: ModId) -> PathBuf {
+        self.dir.join(mod_id.to_string())
+    }
+
+    /// Load the state of the installed mods.
+    pub async fn state(&self) -> Result<State> {
+        let state = fs::read_json(&self.dir.join(STATE_FILE)).await?;
+        Ok(state.unwrap_or_default())
+    }
+
+    async fn save_state(&self, state: &State) -> Result<()> {
+        tokio::fs::create_dir_all(&self.dir)
+            .map_err(error::io)
+            .await?;
+        fs::write_json_atomic(&self.dir.join(STATE_FILE), state).await
+    }
+
+    /// Install the primary modfiles of all subscribed mods and uninstall the mods that are no
+    /// longer subscribed. [required: token]
+    ///
+    /// Mods whose installed modfile is still the primary modfile are skipped. A failing mod
+    /// doesn't stop the sync, its error is returned in [`SyncReport::failed`].
+    pub async fn sync(&self) -> Result<SyncReport> {
+        let filter = GameIdFilter::eq(self.game_id);
+        let subscriptions = self.modio.user().subscriptions(filter).collect().await?;
+
+        let mut report = SyncReport::default();
+        let state = self.state().await?;
+
+        for mod_ in &subscriptions {
+            let file = match &mod_.modfile {
+                Some(file) => file,
+                None => continue,
+            };
+            let list = match state.get(mod_.id) {
+                Some(installed) if installed.file_id == file.id => continue,
+                Some(_) => &mut report.updated,
+                None => &mut report.installed,
+            };
+            match self.install(file.clone()).await {
+                Ok(_) => list.push(mod_.id),
+                Err(e) => report.failed.push((mod_.id, e)),
+            }
+        }
+
+        for installed in state.iter() {
+            if !subscriptions.iter().any(|m| m.id == installed.mod_id) {
+                match self.uninstall(installed.mod_id).await {
+                    Ok(()) => report.removed.push(installed.mod_id),
+                    Err(e) => report.failed.push((installed.mod_id, e)),
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Download, verify and extract a modfile into the directory of its mod.
+    ///
+    /// A previously installed modfile of the mod is replaced. An interrupted download is resumed
+    /// by the next call, a download that fails the verification is deleted.
+    pub async fn install(&self, file: File) -> Result<InstalledMod> {
+        let mod_id = file.mod_id;
+        let file_id = file.id;
+        let version = file.version.clone();
+        let filehash = file.filehash.md5.clone();
+
+        tokio::fs::create_dir_all(&self.dir)
+            .map_err(error::io)
+            .await?;
+
+        let archive = self.dir.join(format!(".{mod_id}-{file_id}.zip"));
+        debug!("installing modfile {file_id} of mod {mod_id}");
+        let downloaded = self
+            .modio
+            .download(DownloadAction::FileObj(Box::new(file)))
+            .await?
+            .verify()
+            .resume_to_file(&archive)
+            .await;
+        if let Err(e) = downloaded {
+            // A corrupt archive would fail the verification of every resumed download.
+            if e.is_download() {
+                let _ = tokio::fs::remove_file(&archive).await;
+            }
+            return Err(e);
+        }
+
+        let target = self.mod_dir(mod_id);
+        let staging = self.dir.join(format!(".{mod_id}-{file_id}"));
+        let result = {
+            let archive = archive.clone();
+            let staging = staging.clone();
+            tokio::task::spawn_blocking(move || extract(&archive, &staging))
+                .await
+                .map_err(error::archive)?
+        };
+        let _ = tokio::fs::remove_file(&archive).await;
+        if let Err(e) = result {
+            remove_dir(&staging).await?;
+            return Err(e);
+        }
+
+        remove_dir(&target).await?;
+        tokio::fs::rename(&staging, &target)
+            .map_err(error::io)
+            .await?;
+
+        let installed = InstalledMod {
+            mod_id,
+            file_id,
+            version,
+            filehash,
+            path: PathBuf::from(mod_id.to_string()),
+        };
+        let mut state = self.state().await?;
+        state.mods.insert(mod_id, installed.clone());
+        self.save_state(&state).await?;
+
+        Ok(installed)
+    }
+
+    /// Remove the installed files of a mod.
+    pub async fn uninstall(&self, mod_id: ModId) -> Result<()> {
+        let mut state = self.state().await?;
+        let path = match state.mods.remove(&mod_id) {
+            Some(installed) => self.dir.join(installed.path),
+            None => self.mod_dir(mod_id),
+        };
+        debug!("uninstalling mod {mod_id}");
+        remove_dir(&path).await?;
+        self.save_state(&state).await
+    }
+}
+
+async fn remove_dir(path: &Path) -> Result<()> {
+    match tokio::fs::remove_dir_all(path).await {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(error::io(e)),
+        _ => Ok(()),
+    }
+}
+
+fn extract(archive: &Path, dir: &Path) -> Result<()> {
+    if dir.exists() {
+        std::fs::remove_dir_all(dir).map_err(error::io)?;
+    }
+    let file = std::fs::File::open(archive).map_err(error::io)?;
+    let mut zip = zip::ZipArchive::new(file).map_err(error::archive)?;
+    zip.extract(dir).map_err(error::archive)
+}
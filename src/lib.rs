@@ -9,10 +9,10 @@
 //!
 //! - Request an [API key (Read-only)](https://mod.io/me/access)
 //! - Manually create an [OAuth 2 Access Token (Read + Write)](https://mod.io/me/access#oauth)
-//! - [Email Authentication Flow](auth::Auth#example) to create an OAuth 2 Access Token
-//! (Read + Write)
+//! - [Email Authentication Flow](auth::EmailFlow) to create an OAuth 2 Access Token
+//!   (Read + Write)
 //! - [External Authentication](auth::Auth::external) to create an OAuth 2 Access Token (Read + Write)
-//! automatically on platforms such as Steam, GOG, itch.io, Switch, Xbox, Discord and Oculus.
+//!   automatically on platforms such as Steam, GOG, itch.io, Switch, Xbox, Discord and Oculus.
 //!
 //! # Rate Limiting
 //!
@@ -23,6 +23,9 @@
 //! [`Error::is_ratelimited`] will return true
 //! if the rate limit associated with credentials has been exhausted.
 //!
+//! Rate limited requests can be retried automatically by configuring a
+//! [`RetryPolicy`](retry::RetryPolicy) with [`Builder::retry_policy`].
+//!
 //! # Example: Basic setup
 //!
 //! ```no_run
@@ -123,6 +126,21 @@
 //! #    Ok(())
 //! # }
 //! ```
+//!
+//! # Optional features
+//!
+//! - `default-tls` (enabled by default), `rustls-tls`: Send the requests with `reqwest` and the
+//!   selected TLS backend. Without these features `reqwest` is not compiled and a custom
+//!   [`Transport`](transport::Transport) must be set with [`Builder::transport`].
+//! - `blocking`: A synchronous client API in the `blocking` module for
+//!   applications that don't use an async runtime.
+//! - `encryption`: Encrypt the credentials saved by `auth::store::FileStore` with AES-256-GCM.
+//! - `testing`: An in-process fake mod.io server in the `testing` module for integration tests
+//!   of applications built on this crate.
+//! - `tower`: Add tower middleware to the request pipeline with `Builder::layer`.
+//! - `zip`: Pack mod directories into zip archives for uploading with
+//!   `AddFileOptions::with_directory` and `AddFileOptions::with_package`, and install the
+//!   subscribed mods into a local directory with `install::Installer`.
 #![doc(html_root_url = "https://docs.rs/modio/0.9.1")]
 #![deny(rust_2018_idioms)]
 #![deny(rustdoc::broken_intra_doc_links)]
@@ -132,23 +150,40 @@
 mod macros;
 
 pub mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 #[macro_use]
 pub mod filter;
+pub mod cache;
 pub mod comments;
 pub mod download;
+pub mod events;
 pub mod files;
 pub mod games;
+#[cfg(feature = "zip")]
+pub mod install;
 pub mod metadata;
+#[cfg(feature = "tower")]
+pub mod middleware;
 pub mod mods;
+pub mod ratelimit;
 pub mod reports;
+pub mod response;
+pub mod retry;
+pub mod sync;
 pub mod teams;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transport;
 pub mod types;
 pub mod user;
 
 mod client;
 mod error;
 mod file_source;
+mod fs;
 mod loader;
+mod multipart;
 mod request;
 mod routing;
 
@@ -161,11 +196,11 @@ pub use crate::types::{Deletion, Editing, TargetPlatform, TargetPortal};
 
 mod prelude {
     pub use futures_util::Stream;
-    pub use reqwest::multipart::Form;
-    pub use reqwest::StatusCode;
+    pub use http::StatusCode;
 
     pub use crate::filter::Filter;
     pub use crate::loader::Query;
+    pub use crate::multipart::Form;
     pub use crate::routing::Route;
     pub use crate::types::Message;
     pub use crate::{Deletion, Editing, Modio, Result};
@@ -174,10 +209,12 @@ mod prelude {
 /// Re-exports of the used reqwest types.
 #[doc(hidden)]
 pub mod lib {
-    pub use reqwest::header;
+    pub use http::header;
+    #[cfg(feature = "__tls")]
     pub use reqwest::redirect::Policy;
+    #[cfg(feature = "__tls")]
     pub use reqwest::ClientBuilder;
     #[cfg(feature = "__tls")]
-    pub use reqwest::{Certificate, Identity};
-    pub use reqwest::{Proxy, Url};
+    pub use reqwest::{Certificate, Identity, Proxy};
+    pub use url::Url;
 }
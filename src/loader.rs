@@ -8,6 +8,7 @@ use pin_project_lite::pin_project;
 use serde::de::DeserializeOwned;
 
 use crate::filter::Filter;
+use crate::response::{Response, ResponseMeta};
 use crate::routing::Route;
 use crate::types::List;
 use crate::{Modio, Result};
@@ -33,21 +34,40 @@ impl<T> Query<T> {
 
 impl<T: DeserializeOwned + Send> Query<T> {
     /// Returns the first search result.
-    pub async fn first(mut self) -> Result<Option<T>> {
+    pub async fn first(self) -> Result<Option<T>> {
+        self.first_with_meta().await.map(Response::into_inner)
+    }
+
+    /// Returns the first search result together with the metadata of the response.
+    pub async fn first_with_meta(mut self) -> Result<Response<Option<T>>> {
         self.filter = self.filter.limit(1);
-        let list = self.first_page().await;
-        list.map(|l| l.into_iter().next())
+        let list = self.first_page_with_meta().await;
+        list.map(|l| l.map(|l| l.into_iter().next()))
     }
 
     /// Returns the first search result page.
     pub async fn first_page(self) -> Result<Vec<T>> {
-        let list = self.paged().await?.map_ok(|p| p.0.data).try_next().await;
-        list.map(Option::unwrap_or_default)
+        self.first_page_with_meta().await.map(Response::into_inner)
+    }
+
+    /// Returns the first search result page together with the metadata of the response.
+    pub async fn first_page_with_meta(self) -> Result<Response<Vec<T>>> {
+        let list = self
+            .modio
+            .request(self.route)
+            .query(&self.filter)
+            .send_with_meta::<List<T>>()
+            .await;
+        list.map(|l| l.map(|l| l.data))
     }
 
     /// Returns the complete search result list.
     pub async fn collect(self) -> Result<Vec<T>> {
-        self.paged().await?.map_ok(|p| p.0.data).try_concat().await
+        self.paged()
+            .await?
+            .map_ok(|p| p.into_data())
+            .try_concat()
+            .await
     }
 
     /// Provides a stream over all search result items.
@@ -150,11 +170,12 @@ where
         limit: u32,
         remaining: u32,
     }
-    let list = modio
+    let (list, meta) = modio
         .request(route)
         .query(&filter)
-        .send::<List<T>>()
-        .await?;
+        .send_with_meta::<List<T>>()
+        .await?
+        .into_parts();
 
     let state = State {
         offset: list.offset,
@@ -167,7 +188,7 @@ where
         return Ok((Either::Left(stream::empty()), stats));
     }
 
-    let first = stream::once(async { Ok::<_, crate::Error>(Page(list)) });
+    let first = stream::once(async { Ok::<_, crate::Error>(Page(list, meta)) });
 
     let others = stream::try_unfold(initial, |(modio, route, filter, state)| async move {
         if let State { remaining: 0, .. } = state {
@@ -176,11 +197,12 @@ where
         let filter = filter.offset((state.offset + state.limit) as usize);
         let remaining = state.remaining;
 
-        let list = modio
+        let (list, meta) = modio
             .request(route)
             .query(&filter)
-            .send::<List<T>>()
-            .await?;
+            .send_with_meta::<List<T>>()
+            .await?
+            .into_parts();
 
         let state = (
             modio,
@@ -193,14 +215,14 @@ where
             },
         );
 
-        Ok(Some((Page(list), state)))
+        Ok(Some((Page(list, meta), state)))
     });
 
     Ok((Either::Right(first.chain(others)), stats))
 }
 
 /// A `Page` returned by the [`Query::paged`] stream for a search result.
-pub struct Page<T>(List<T>);
+pub struct Page<T>(List<T>, Option<ResponseMeta>);
 
 impl<T> Page<T> {
     pub fn data(&self) -> &Vec<T> {
@@ -230,6 +252,13 @@ impl<T> Page<T> {
     pub fn total(&self) -> usize {
         self.0.total as usize
     }
+
+    /// Returns the metadata of the response of the page.
+    ///
+    /// Returns `None` if the page was loaded from a fresh [cached](crate::cache) response.
+    pub fn meta(&self) -> Option<&ResponseMeta> {
+        self.1.as_ref()
+    }
 }
 
 // Impl IntoIterator & Deref for Page<T> {{{
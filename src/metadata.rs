@@ -55,7 +55,7 @@ impl Metadata {
         };
         self.modio
             .request(route)
-            .form(&metadata)
+            .form(&MetadataForm(&metadata))
             .send::<Message>()
             .await?;
         Ok(())
@@ -67,21 +67,37 @@ impl Metadata {
             game_id: self.game,
             mod_id: self.mod_id,
         };
-        self.modio.request(route).form(&metadata).send().await
+        self.modio
+            .request(route)
+            .form(&MetadataForm(&metadata))
+            .send()
+            .await
+    }
+}
+
+/// Form encoding of a `MetadataMap` as `metadata[]=key:value` pairs.
+///
+/// This is the encoding of the add and delete requests. The `Serialize` implementation of
+/// `MetadataMap` itself produces the JSON shape of the API responses.
+#[derive(Clone, Copy, Debug)]
+pub struct MetadataForm<'a>(&'a MetadataMap);
+
+impl<'a> MetadataForm<'a> {
+    pub fn new(metadata: &'a MetadataMap) -> Self {
+        Self(metadata)
     }
 }
 
-#[doc(hidden)]
-impl serde::ser::Serialize for MetadataMap {
+impl serde::ser::Serialize for MetadataForm<'_> {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::ser::Serializer,
     {
         use serde::ser::SerializeMap;
 
-        let len = self.values().map(|v| std::cmp::max(1, v.len())).sum();
+        let len = self.0.values().map(|v| std::cmp::max(1, v.len())).sum();
         let mut map = serializer.serialize_map(Some(len))?;
-        for (k, vals) in self.iter() {
+        for (k, vals) in self.0.iter() {
             if vals.is_empty() {
                 map.serialize_entry("metadata[]", k)?;
             }
@@ -92,3 +108,26 @@ impl serde::ser::Serialize for MetadataMap {
         map.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_ser_tokens, Token};
+
+    use super::{MetadataForm, MetadataMap};
+
+    #[test]
+    fn form_encoding() {
+        let mut metadata = MetadataMap::new();
+        metadata.insert("pistol-dmg".to_owned(), vec!["800".to_owned()]);
+
+        assert_ser_tokens(
+            &MetadataForm::new(&metadata),
+            &[
+                Token::Map { len: Some(1) },
+                Token::Str("metadata[]"),
+                Token::Str("pistol-dmg:800"),
+                Token::MapEnd,
+            ],
+        );
+    }
+}
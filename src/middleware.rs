@@ -0,0 +1,96 @@
+//! Tower middleware for the request pipeline.
+//!
+//! The [`Transport`] of a client is wrapped in a [`TransportService`] and the layers added with
+//! [`Builder::layer`] are applied on top of it. Every request, including each retry of a failed
+//! request, downloads and uploads, passes through the layer stack.
+//!
+//! [`Builder::layer`]: crate::Builder::layer
+//!
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use modio::Modio;
+//! use tower::ServiceBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let modio = Modio::builder("api-key")
+//!     .layer(
+//!         ServiceBuilder::new()
+//!             .concurrency_limit(4)
+//!             .timeout(Duration::from_secs(30)),
+//!     )
+//!     .build()?;
+//! #    Ok(())
+//! # }
+//! ```
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures_util::future::BoxFuture;
+use tower::util::BoxCloneService;
+use tower::{Layer, Service, ServiceExt};
+
+use crate::transport::{BoxError, Request, Response, Transport};
+
+/// A type-erased service of the layer stack.
+pub type BoxService = BoxCloneService<Request, Response, BoxError>;
+
+pub(crate) type LayerFn = Box<dyn FnOnce(BoxService) -> BoxService + Send>;
+
+/// The innermost service of the layer stack that sends the requests with a [`Transport`].
+#[derive(Clone, Debug)]
+pub struct TransportService {
+    transport: Arc<dyn Transport>,
+}
+
+impl Service<Request> for TransportService {
+    type Response = Response;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<Response, BoxError>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        self.transport.execute(request)
+    }
+}
+
+/// Sends the requests through the layer stack.
+struct ServiceTransport {
+    service: Mutex<BoxService>,
+}
+
+impl Transport for ServiceTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'static, Result<Response, BoxError>> {
+        let service = self.service.lock().expect("service poisoned").clone();
+        Box::pin(service.oneshot(request))
+    }
+}
+
+pub(crate) fn layer_fn<L>(layer: L) -> LayerFn
+where
+    L: Layer<BoxService> + Send + 'static,
+    L::Service: Service<Request, Response = Response> + Clone + Send + 'static,
+    <L::Service as Service<Request>>::Error: Into<BoxError>,
+    <L::Service as Service<Request>>::Future: Send + 'static,
+{
+    Box::new(move |service| BoxCloneService::new(layer.layer(service).map_err(Into::into)))
+}
+
+/// Wrap the transport with the layers. The first layer is the outermost layer.
+pub(crate) fn apply(transport: Arc<dyn Transport>, layers: Vec<LayerFn>) -> Arc<dyn Transport> {
+    if layers.is_empty() {
+        return transport;
+    }
+    let service = BoxCloneService::new(TransportService { transport });
+    let service = layers
+        .into_iter()
+        .rev()
+        .fold(service, |service, f| f(service));
+    Arc::new(ServiceTransport {
+        service: Mutex::new(service),
+    })
+}
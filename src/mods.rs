@@ -6,10 +6,12 @@ use mime::{APPLICATION_OCTET_STREAM, IMAGE_STAR};
 use url::Url;
 
 use crate::comments::Comments;
+use crate::events::EventStream;
 use crate::file_source::FileSource;
 use crate::files::{FileRef, Files};
 use crate::metadata::Metadata;
 use crate::prelude::*;
+use crate::response::Response;
 use crate::teams::Members;
 use crate::types::id::{FileId, GameId, ModId};
 
@@ -72,6 +74,14 @@ impl Mods {
         let route = Route::GetModsEvents { game_id: self.game };
         Query::new(self.modio, route, filter)
     }
+
+    /// Returns a long-lived stream of the events of all mods of the game.
+    ///
+    /// See [`EventStream`] and [Filters and sorting](filters::events).
+    pub fn event_stream(self, filter: Filter) -> EventStream<Event> {
+        let route = Route::GetModsEvents { game_id: self.game };
+        EventStream::new(self.modio, route, filter)
+    }
 }
 
 /// Reference interface of a mod.
@@ -96,6 +106,15 @@ impl ModRef {
         self.modio.request(route).send().await
     }
 
+    /// Get the Modio mod object together with the metadata of the response.
+    pub async fn get_with_meta(self) -> Result<Response<Mod>> {
+        let route = Route::GetMod {
+            game_id: self.game,
+            mod_id: self.id,
+        };
+        self.modio.request(route).send_with_meta().await
+    }
+
     /// Return a reference to an interface that provides access to the files of a mod.
     pub fn files(&self) -> Files {
         Files::new(self.modio.clone(), self.game, self.id)
@@ -146,6 +165,17 @@ impl ModRef {
         Query::new(self.modio, route, filter)
     }
 
+    /// Returns a long-lived stream of the events of the mod.
+    ///
+    /// See [`EventStream`] and [Filters and sorting](filters::events).
+    pub fn event_stream(self, filter: Filter) -> EventStream<Event> {
+        let route = Route::GetModEvents {
+            game_id: self.game,
+            mod_id: self.id,
+        };
+        EventStream::new(self.modio, route, filter)
+    }
+
     /// Return a reference to an interface to manage team members of a mod.
     pub fn members(&self) -> Members {
         Members::new(self.modio.clone(), self.game, self.id)
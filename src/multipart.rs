@@ -0,0 +1,191 @@
+//! Encoding of `multipart/form-data` request bodies.
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use bytes::{Bytes, BytesMut};
+use futures_util::stream::{self, StreamExt};
+use mime::Mime;
+
+use crate::transport::Body;
+
+/// A `multipart/form-data` request body.
+pub struct Form {
+    boundary: String,
+    parts: Vec<(Cow<'static, str>, Part)>,
+}
+
+/// A field of a multipart [`Form`].
+pub struct Part {
+    body: Body,
+    file_name: Option<String>,
+    mime: Option<Mime>,
+}
+
+impl Form {
+    pub fn new() -> Form {
+        let boundary = format!("{:016x}-{:016x}", fastrand::u64(..), fastrand::u64(..));
+        Form {
+            boundary,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Add a text field.
+    pub fn text<N, V>(self, name: N, value: V) -> Form
+    where
+        N: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        self.part(name, Part::text(value))
+    }
+
+    /// Add a custom field.
+    pub fn part<N: Into<Cow<'static, str>>>(mut self, name: N, part: Part) -> Form {
+        self.parts.push((name.into(), part));
+        self
+    }
+
+    /// Returns the value of the `Content-Type` header with the boundary of the form.
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Encode the form as request body.
+    ///
+    /// The body is only a stream if one of the fields is a stream.
+    pub fn into_body(self) -> Body {
+        let boundary = self.boundary;
+        let mut chunks = Vec::with_capacity(self.parts.len() * 3 + 1);
+        for (name, part) in self.parts {
+            chunks.push(Body::from(part_header(&boundary, &name, &part)));
+            chunks.push(part.body);
+            chunks.push(Body::from("\r\n"));
+        }
+        chunks.push(Body::from(format!("--{boundary}--\r\n")));
+
+        if chunks.iter().all(|c| c.as_bytes().is_some()) {
+            let mut buf = BytesMut::new();
+            for chunk in &chunks {
+                buf.extend_from_slice(chunk.as_bytes().unwrap_or_default());
+            }
+            Body::from(buf.freeze())
+        } else {
+            Body::wrap_stream(stream::iter(chunks).flat_map(Body::into_stream))
+        }
+    }
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Part {
+    /// Create a text field.
+    pub fn text<V: Into<Cow<'static, str>>>(value: V) -> Part {
+        let body = match value.into() {
+            Cow::Borrowed(s) => Body::from(s),
+            Cow::Owned(s) => Body::from(s),
+        };
+        Part::stream(body)
+    }
+
+    /// Create a field from a request body.
+    pub fn stream<B: Into<Body>>(body: B) -> Part {
+        Part {
+            body: body.into(),
+            file_name: None,
+            mime: None,
+        }
+    }
+
+    /// Set the file name of the field.
+    pub fn file_name<S: Into<String>>(self, file_name: S) -> Part {
+        Part {
+            file_name: Some(file_name.into()),
+            ..self
+        }
+    }
+
+    /// Set the content type of the field.
+    pub fn mime(self, mime: Mime) -> Part {
+        Part {
+            mime: Some(mime),
+            ..self
+        }
+    }
+}
+
+fn part_header(boundary: &str, name: &str, part: &Part) -> Bytes {
+    let mut header = format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"",
+        escape(name)
+    );
+    if let Some(file_name) = &part.file_name {
+        let _ = write!(header, "; filename=\"{}\"", escape(file_name));
+    }
+    if let Some(mime) = &part.mime {
+        let _ = write!(header, "\r\nContent-Type: {mime}");
+    }
+    header.push_str("\r\n\r\n");
+    Bytes::from(header)
+}
+
+/// Escape the quotes and line breaks of a field or file name.
+fn escape(value: &str) -> Cow<'_, str> {
+    if value.contains(['"', '\r', '\n']) {
+        let value = value
+            .replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A");
+        Cow::Owned(value)
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::stream;
+
+    use super::{Form, Part};
+    use crate::transport::Body;
+
+    #[tokio::test]
+    async fn encode_form() {
+        let file = Body::wrap_stream(stream::iter([Ok::<_, std::io::Error>("data".into())]));
+        let form = Form::new().text("name", "foo \"bar\"").part(
+            "filedata",
+            Part::stream(file)
+                .file_name("mod.zip")
+                .mime(mime::APPLICATION_OCTET_STREAM),
+        );
+        let boundary = form.boundary.clone();
+        assert_eq!(
+            form.content_type(),
+            format!("multipart/form-data; boundary={boundary}")
+        );
+
+        let body = form.into_body();
+        assert!(body.as_bytes().is_none());
+        let body = body.bytes().await.unwrap();
+        let expected = format!(
+            "--{boundary}\r\n\
+             Content-Disposition: form-data; name=\"name\"\r\n\r\n\
+             foo \"bar\"\r\n\
+             --{boundary}\r\n\
+             Content-Disposition: form-data; name=\"filedata\"; filename=\"mod.zip\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n\
+             data\r\n\
+             --{boundary}--\r\n"
+        );
+        assert_eq!(body, expected.as_bytes());
+    }
+
+    #[test]
+    fn text_only_form_is_not_a_stream() {
+        let body = Form::new().text("youtube[]", "url").into_body();
+        assert!(body.as_bytes().is_some());
+    }
+}
@@ -0,0 +1,186 @@
+//! Client-side rate limiting.
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::{sleep, Instant};
+use tracing::debug;
+
+const ONE_MINUTE: Duration = Duration::from_secs(60);
+
+/// Configuration of the client-side rate limiter.
+///
+/// The limiter is a token bucket shared by all clones of a `Modio` client. Every request waits
+/// for a token before it is sent. The bucket holds up to `burst` tokens and is refilled at a
+/// rate of `requests_per_minute`.
+///
+/// When the API responds with a rate limit error, the bucket is paused for the duration of the
+/// `retry-after` header.
+///
+/// See the [rate limiting](crate#rate-limiting) section for the quotas enforced by mod.io.
+///
+/// # Example
+/// ```no_run
+/// use modio::ratelimit::RateLimit;
+/// use modio::Modio;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let modio = Modio::builder("api-key")
+///     .rate_limit(RateLimit::per_minute(60).burst(10))
+///     .build()?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimit {
+    requests_per_minute: u32,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Create a new rate limit of `requests_per_minute`.
+    ///
+    /// The burst size defaults to `requests_per_minute`.
+    pub fn per_minute(requests_per_minute: u32) -> Self {
+        let requests_per_minute = requests_per_minute.max(1);
+        Self {
+            requests_per_minute,
+            burst: requests_per_minute,
+        }
+    }
+
+    /// Set the maximum number of requests that can be sent at once.
+    #[must_use]
+    pub fn burst(self, burst: u32) -> Self {
+        Self {
+            burst: burst.max(1),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Limiter {
+    config: RateLimit,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    tokens: f64,
+    last_refill: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl Limiter {
+    pub(crate) fn new(config: RateLimit) -> Self {
+        Self {
+            config,
+            state: Mutex::new(State {
+                tokens: f64::from(config.burst),
+                last_refill: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    /// Create a new limiter with the same configuration and a full bucket.
+    pub(crate) fn reset(&self) -> Self {
+        Self::new(self.config)
+    }
+
+    /// Wait until a token is available and take it.
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().expect("ratelimit state poisoned");
+                let now = Instant::now();
+                self.refill(&mut state, now);
+
+                match state.paused_until {
+                    Some(until) if until > now => until - now,
+                    _ if state.tokens >= 1.0 => {
+                        state.tokens -= 1.0;
+                        return;
+                    }
+                    _ => {
+                        let missing = 1.0 - state.tokens;
+                        ONE_MINUTE.mul_f64(missing / f64::from(self.config.requests_per_minute))
+                    }
+                }
+            };
+            debug!("ratelimit: waiting {wait:?} for the next request");
+            sleep(wait).await;
+        }
+    }
+
+    /// Pause the bucket for `duration` and resume with a single token.
+    pub(crate) fn pause(&self, duration: Duration) {
+        let mut state = self.state.lock().expect("ratelimit state poisoned");
+        let until = Instant::now() + duration;
+        let until = state.paused_until.map_or(until, |u| u.max(until));
+        state.tokens = 1.0;
+        state.last_refill = until;
+        state.paused_until = Some(until);
+    }
+
+    fn refill(&self, state: &mut State, now: Instant) {
+        if now <= state.last_refill {
+            return;
+        }
+        let elapsed = now - state.last_refill;
+        let rate = f64::from(self.config.requests_per_minute) / ONE_MINUTE.as_secs_f64();
+        let tokens = state.tokens + elapsed.as_secs_f64() * rate;
+        state.tokens = tokens.min(f64::from(self.config.burst));
+        state.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::{Limiter, RateLimit};
+
+    #[tokio::test(start_paused = true)]
+    async fn burst_then_refill() {
+        let limiter = Limiter::new(RateLimit::per_minute(60).burst(2));
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert!(start.elapsed() < Duration::from_millis(1100));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pause_resumes_with_single_token() {
+        let limiter = Limiter::new(RateLimit::per_minute(60).burst(10));
+        let start = Instant::now();
+
+        limiter.pause(Duration::from_secs(5));
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_secs(6));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shorter_pause_keeps_longer_deadline() {
+        let limiter = Limiter::new(RateLimit::per_minute(60).burst(10));
+        let start = Instant::now();
+
+        limiter.pause(Duration::from_secs(10));
+        limiter.pause(Duration::from_secs(2));
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(10));
+
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_secs(11));
+    }
+}
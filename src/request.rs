@@ -1,36 +1,34 @@
-use futures_util::TryFutureExt;
-use reqwest::header::{HeaderValue, CONTENT_TYPE};
-use reqwest::multipart::Form;
-use reqwest::StatusCode;
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use http::header::{AUTHORIZATION, CONTENT_TYPE, IF_NONE_MATCH};
+use http::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 use tracing::{debug, level_enabled, trace};
 use url::Url;
 
-use crate::auth::Token;
+use crate::auth::{Credentials, Token};
+use crate::cache::{Cache, CachedResponse};
 use crate::error::{self, Result};
+use crate::multipart::Form;
+use crate::response::{Response, ResponseMeta};
 use crate::routing::{Parts, Route};
+use crate::transport::{self, Body, Request};
 use crate::types::ErrorResponse;
 use crate::Modio;
 
-#[allow(dead_code)]
-mod headers {
-    const X_MODIO_ERROR_REF: &str = "x-modio-error-ref";
-    const X_MODIO_REQUEST_ID: &str = "x-modio-request-id";
-
-    use http::header::{HeaderMap, RETRY_AFTER};
-
-    pub fn retry_after(headers: &HeaderMap) -> Option<u64> {
-        headers
-            .get(RETRY_AFTER)
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.parse().ok())
-    }
-}
-
 pub struct RequestBuilder {
     modio: Modio,
-    request: Result<reqwest::RequestBuilder>,
+    /// The access token of the request if the route requires one.
+    token: Option<Token>,
+    request: Result<RequestParts>,
+}
+
+/// The parts of a request that is not built yet.
+struct RequestParts {
+    method: Method,
+    url: Url,
+    headers: HeaderMap,
+    body: Body,
 }
 
 impl RequestBuilder {
@@ -41,103 +39,303 @@ impl RequestBuilder {
             token_required,
         } = route.into_parts();
 
-        if let (true, None) = (token_required, &modio.inner.credentials.token) {
-            return Self {
-                modio,
-                request: Err(error::token_required()),
-            };
-        }
+        let credentials = modio.inner.session.credentials();
+        let token = match (token_required, credentials.token) {
+            (true, None) => {
+                return Self {
+                    modio,
+                    token: None,
+                    request: Err(error::token_required()),
+                };
+            }
+            (true, token) => token,
+            (false, _) => None,
+        };
 
         let url = format!("{}{}", modio.inner.host, path);
-        let params = [("api_key", &modio.inner.credentials.api_key)];
+        let params = [("api_key", &credentials.api_key)];
         let request = Url::parse_with_params(&url, &params)
-            .map(|url| {
-                let mut req = modio.inner.client.request(method, url);
-
-                if let (true, Some(Token { value, .. })) =
-                    (token_required, &modio.inner.credentials.token)
-                {
-                    req = req.bearer_auth(value);
+            .map_err(error::builder)
+            .and_then(|url| {
+                let mut headers = HeaderMap::new();
+                if let Some(Token { value, .. }) = &token {
+                    headers.insert(AUTHORIZATION, bearer_auth(value)?);
                 }
-                req
-            })
-            .map_err(error::builder);
+                Ok(RequestParts {
+                    method,
+                    url,
+                    headers,
+                    body: Body::empty(),
+                })
+            });
 
-        Self { modio, request }
+        Self {
+            modio,
+            token,
+            request,
+        }
     }
 
     pub fn query<T: Serialize + ?Sized>(self, query: &T) -> Self {
-        Self {
-            request: self.request.map(|r| r.query(query)),
-            ..self
-        }
+        let request = self.request.and_then(|mut r| {
+            let mut pairs = r.url.query_pairs_mut();
+            query
+                .serialize(serde_urlencoded::Serializer::new(&mut pairs))
+                .map_err(error::builder)?;
+            drop(pairs);
+            Ok(r)
+        });
+        Self { request, ..self }
     }
 
     pub fn form<T: Serialize + ?Sized>(self, form: &T) -> Self {
-        Self {
-            request: self.request.map(|r| r.form(form)),
-            ..self
-        }
+        let request = self.request.and_then(|mut r| {
+            let body = serde_urlencoded::to_string(form).map_err(error::builder)?;
+            r.headers.insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/x-www-form-urlencoded"),
+            );
+            r.body = Body::from(body);
+            Ok(r)
+        });
+        Self { request, ..self }
     }
 
     pub fn multipart(self, form: Form) -> Self {
-        Self {
-            request: self.request.map(|r| r.multipart(form)),
-            ..self
-        }
+        let request = self.request.and_then(|mut r| {
+            let content_type =
+                HeaderValue::from_str(&form.content_type()).map_err(error::builder)?;
+            r.headers.insert(CONTENT_TYPE, content_type);
+            r.body = form.into_body();
+            Ok(r)
+        });
+        Self { request, ..self }
+    }
+
+    pub fn header(self, name: HeaderName, value: HeaderValue) -> Self {
+        let request = self.request.map(|mut r| {
+            r.headers.insert(name, value);
+            r
+        });
+        Self { request, ..self }
+    }
+
+    pub fn body<B: Into<Body>>(self, body: B) -> Self {
+        let request = self.request.map(|r| RequestParts {
+            body: body.into(),
+            ..r
+        });
+        Self { request, ..self }
     }
 
     pub async fn send<Out>(self) -> Result<Out>
     where
         Out: DeserializeOwned + Send,
     {
-        let mut req = self.request?.build().map_err(error::builder)?;
+        self.send_with_meta().await.map(Response::into_inner)
+    }
+
+    pub async fn send_with_meta<Out>(self) -> Result<Response<Out>>
+    where
+        Out: DeserializeOwned + Send,
+    {
+        let mut req = self.request?.build()?;
+
+        let session = &self.modio.inner.session;
+        let mut token = self.token;
+        let mut reauthenticated = false;
+        if let Some(stale) = token.as_ref().filter(|t| t.is_expired()) {
+            if session.can_reauthenticate() {
+                let credentials = session.reauthenticate(&self.modio, Some(stale)).await?;
+                set_credentials(&mut req, &credentials)?;
+                token = credentials.token;
+                reauthenticated = true;
+            }
+        }
+
+        // The request is not borrowed across the lookup since a streaming body isn't `Sync`.
+        let key = self
+            .modio
+            .inner
+            .cache
+            .as_ref()
+            .and_then(|_| Cache::key(&req));
+        let cached = match (&self.modio.inner.cache, key) {
+            (Some(cache), Some(key)) => Some(cache.lookup(key).await),
+            _ => None,
+        };
+        if let Some((_, Some(cached))) = &cached {
+            if cached.is_fresh() {
+                debug!("cached response: {} {}", req.method(), req.uri());
+                let value = serde_json::from_slice(&cached.body).map_err(error::decode)?;
+                return Ok(Response::new(value, None));
+            }
+            if let Some(etag) = cached.if_none_match() {
+                req.headers_mut().insert(IF_NONE_MATCH, etag);
+            }
+        }
+
+        let policy = &self.modio.inner.retry_policy;
+        let mut attempt = 0;
+        loop {
+            // An unauthorized request is retried once with the credentials of the callback.
+            let reauthenticate =
+                token.is_some() && !reauthenticated && session.can_reauthenticate();
+
+            // Requests with a streaming body can't be cloned and are never retried.
+            let retry = if attempt < policy.max_retries() || reauthenticate {
+                transport::try_clone(&req)
+            } else {
+                None
+            };
+            let method = req.method().clone();
+
+            if let Some(limiter) = &self.modio.inner.limiter {
+                limiter.acquire().await;
+            }
+            let err = match execute(&self.modio, req, cached.as_ref()).await {
+                Ok(out) => return Ok(out),
+                Err(e) => e,
+            };
+            if let (Some(limiter), Some(retry_after)) =
+                (&self.modio.inner.limiter, err.retry_after())
+            {
+                limiter.pause(retry_after);
+            }
+            let retry = match retry {
+                Some(mut next) if reauthenticate && err.is_auth() => {
+                    let credentials = session.reauthenticate(&self.modio, token.as_ref()).await?;
+                    set_credentials(&mut next, &credentials)?;
+                    token = credentials.token;
+                    reauthenticated = true;
+                    debug!("retrying request with new credentials: {err}");
+                    req = next;
+                    continue;
+                }
+                retry => retry,
+            };
+            match (retry, policy.retry_delay(attempt, &method, &err)) {
+                (Some(next), Some(delay)) => {
+                    attempt += 1;
+                    debug!("retrying request in {delay:?} (attempt {attempt}): {err}");
+                    tokio::time::sleep(delay).await;
+                    req = next;
+                }
+                _ => return Err(err),
+            }
+        }
+    }
+}
+
+impl RequestParts {
+    fn build(self) -> Result<Request> {
+        let mut req = http::Request::builder()
+            .method(self.method)
+            .uri(self.url.as_str())
+            .body(self.body)
+            .map_err(error::builder)?;
+        *req.headers_mut() = self.headers;
         if !req.headers().contains_key(CONTENT_TYPE) {
             req.headers_mut().insert(
                 CONTENT_TYPE,
                 HeaderValue::from_static("application/x-www-form-urlencoded"),
             );
         }
+        Ok(req)
+    }
+}
 
-        debug!("request: {} {}", req.method(), req.url());
-        let response = self
-            .modio
-            .inner
-            .client
-            .execute(req)
-            .map_err(error::request)
-            .await?;
+fn bearer_auth(token: &str) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {token}")).map_err(error::builder)?;
+    value.set_sensitive(true);
+    Ok(value)
+}
 
-        let status = response.status();
+/// Replaces the api key and the access token of a request.
+fn set_credentials(req: &mut Request, credentials: &Credentials) -> Result<()> {
+    let mut url = Url::parse(&req.uri().to_string()).map_err(error::builder)?;
+    let query = url
+        .query_pairs()
+        .map(|(k, v)| match &*k {
+            "api_key" => (k.into_owned(), credentials.api_key.clone()),
+            _ => (k.into_owned(), v.into_owned()),
+        })
+        .collect::<Vec<_>>();
+    url.query_pairs_mut().clear().extend_pairs(query);
+    *req.uri_mut() = url.as_str().parse().map_err(error::builder)?;
 
-        let retry_after = if status.is_success() {
-            None
-        } else {
-            headers::retry_after(response.headers())
-        };
+    if let (true, Some(Token { value, .. })) = (
+        req.headers().contains_key(AUTHORIZATION),
+        &credentials.token,
+    ) {
+        req.headers_mut().insert(AUTHORIZATION, bearer_auth(value)?);
+    }
+    Ok(())
+}
+
+async fn execute<Out>(
+    modio: &Modio,
+    req: Request,
+    cached: Option<&(String, Option<CachedResponse>)>,
+) -> Result<Response<Out>>
+where
+    Out: DeserializeOwned + Send,
+{
+    debug!("request: {} {}", req.method(), req.uri());
+    let mutation = (req.method() != Method::GET).then(|| req.uri().clone());
+    let response = transport::send(modio, req).await?;
+    let (parts, body) = response.into_parts();
+    let mut status = parts.status;
+
+    let meta = ResponseMeta::new(status, &parts.headers);
 
-        trace!("response headers: {:?}", response.headers());
+    trace!("response headers: {:?}", parts.headers);
 
-        let body = response.bytes().map_err(error::request).await?;
+    let mut body = match body.bytes().await {
+        Ok(body) => body,
+        Err(e) => return Err(error::request(e).with_meta(meta)),
+    };
 
-        if level_enabled!(tracing::Level::TRACE) {
-            match std::str::from_utf8(&body) {
-                Ok(s) => trace!("status: {}, response: {}", status, s),
-                Err(_) => trace!("status: {}, response: {:?}", status, body),
+    if let (Some(cache), Some((key, cached))) = (&modio.inner.cache, cached) {
+        match cached {
+            Some(cached) if status == StatusCode::NOT_MODIFIED => {
+                debug!("cached response is not modified");
+                cache.refresh(key, &parts.headers, cached).await;
+                status = StatusCode::OK;
+                body = cached.body.clone();
             }
+            _ if status == StatusCode::OK => cache.store(key, &parts.headers, body.clone()).await,
+            _ => {}
+        }
+    }
+    if let (Some(cache), Some(url)) = (&modio.inner.cache, mutation) {
+        if status.is_success() {
+            cache.invalidate(&url).await;
         }
+    }
+
+    if level_enabled!(tracing::Level::TRACE) {
+        match std::str::from_utf8(&body) {
+            Ok(s) => trace!("status: {}, response: {}", status, s),
+            Err(_) => trace!("status: {}, response: {:?}", status, body),
+        }
+    }
 
-        if status == StatusCode::NO_CONTENT {
-            serde_json::from_str("null").map_err(error::decode)
-        } else if status.is_success() {
-            serde_json::from_slice(&body).map_err(error::decode)
-        } else if let Some(retry_after) = retry_after {
-            debug!("ratelimit reached: retry after {retry_after} seconds");
-            Err(error::ratelimit(retry_after))
-        } else {
-            serde_json::from_slice::<ErrorResponse>(&body)
-                .map(|mer| Err(error::error_for_status(status, mer.error)))
-                .map_err(error::decode)?
+    let result = if status == StatusCode::NO_CONTENT {
+        serde_json::from_str("null").map_err(error::decode)
+    } else if status.is_success() {
+        serde_json::from_slice(&body).map_err(error::decode)
+    } else if let Some(retry_after) = meta.retry_after_secs() {
+        debug!("ratelimit reached: retry after {retry_after} seconds");
+        Err(error::ratelimit(retry_after))
+    } else {
+        match serde_json::from_slice::<ErrorResponse>(&body) {
+            Ok(mer) => Err(error::error_for_status(status, mer.error)),
+            Err(e) => Err(error::decode(e)),
         }
+    };
+    match result {
+        Ok(value) => Ok(Response::new(value, Some(meta))),
+        Err(e) => Err(e.with_meta(meta)),
     }
 }
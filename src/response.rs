@@ -0,0 +1,195 @@
+//! Response metadata.
+//!
+//! Every response of the mod.io API carries a request id and the rate limit status of the used
+//! credentials. The metadata of failed requests is available with [`Error::response_meta`].
+//! Successful requests return the metadata with [`Page::meta`] or as [`Response`] with the
+//! `*_with_meta` methods, e.g. [`Query::first_page_with_meta`] for every search result and
+//! [`ModRef::get_with_meta`].
+//!
+//! [`Error::response_meta`]: crate::Error::response_meta
+//! [`Page::meta`]: crate::Page::meta
+//! [`Query::first_page_with_meta`]: crate::Query::first_page_with_meta
+//! [`ModRef::get_with_meta`]: crate::mods::ModRef::get_with_meta
+//!
+//! # Example
+//! ```no_run
+//! use modio::types::id::Id;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let modio = modio::Modio::new("api-key")?;
+//! match modio.mod_(Id::new(51), Id::new(1)).get_with_meta().await {
+//!     Ok(resp) => {
+//!         if let Some(meta) = resp.meta() {
+//!             println!("remaining requests: {:?}", meta.ratelimit_remaining());
+//!         }
+//!         println!("mod: {}", resp.name);
+//!     }
+//!     Err(e) => {
+//!         let request_id = e.request_id().unwrap_or("-");
+//!         eprintln!("request {request_id} failed: {e}");
+//!     }
+//! }
+//!
+//! let files = modio.mod_(Id::new(51), Id::new(1)).files();
+//! let page = files.search(Default::default()).first_page_with_meta().await?;
+//! println!("files: {}, request id: {:?}", page.len(), page.request_id());
+//! #     Ok(())
+//! # }
+//! ```
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+use http::header::{HeaderMap, RETRY_AFTER};
+use http::StatusCode;
+
+const X_MODIO_ERROR_REF: &str = "x-modio-error-ref";
+const X_MODIO_REQUEST_ID: &str = "x-modio-request-id";
+const X_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+const X_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+
+/// Metadata of a response from the mod.io API.
+#[derive(Clone, Debug)]
+pub struct ResponseMeta {
+    status: StatusCode,
+    request_id: Option<String>,
+    error_ref: Option<u16>,
+    ratelimit_limit: Option<u32>,
+    ratelimit_remaining: Option<u32>,
+    retry_after: Option<u64>,
+}
+
+impl ResponseMeta {
+    pub(crate) fn new(status: StatusCode, headers: &HeaderMap) -> Self {
+        Self {
+            status,
+            request_id: header(headers, X_MODIO_REQUEST_ID),
+            error_ref: header(headers, X_MODIO_ERROR_REF),
+            ratelimit_limit: header(headers, X_RATELIMIT_LIMIT),
+            ratelimit_remaining: header(headers, X_RATELIMIT_REMAINING),
+            retry_after: header(headers, RETRY_AFTER.as_str()),
+        }
+    }
+
+    /// Returns the status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the request id assigned by mod.io.
+    ///
+    /// Include the request id in support requests to mod.io.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// Returns modio's error reference code from the response headers.
+    pub fn error_ref(&self) -> Option<u16> {
+        self.error_ref
+    }
+
+    /// Returns the number of requests allowed per rate limit window.
+    pub fn ratelimit_limit(&self) -> Option<u32> {
+        self.ratelimit_limit
+    }
+
+    /// Returns the number of requests remaining in the current rate limit window.
+    pub fn ratelimit_remaining(&self) -> Option<u32> {
+        self.ratelimit_remaining
+    }
+
+    /// Returns the duration to wait before retrying if the rate limit has been exhausted.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after.map(Duration::from_secs)
+    }
+
+    pub(crate) fn retry_after_secs(&self) -> Option<u64> {
+        self.retry_after
+    }
+}
+
+/// A typed result together with the metadata of its response.
+///
+/// `Response` dereferences to the wrapped value.
+#[derive(Clone, Debug)]
+pub struct Response<T> {
+    value: T,
+    meta: Option<ResponseMeta>,
+}
+
+impl<T> Response<T> {
+    pub(crate) fn new(value: T, meta: Option<ResponseMeta>) -> Self {
+        Self { value, meta }
+    }
+
+    /// Returns the metadata of the response.
+    ///
+    /// Returns `None` if the value was loaded from a fresh [cached](crate::cache) response.
+    pub fn meta(&self) -> Option<&ResponseMeta> {
+        self.meta.as_ref()
+    }
+
+    /// Returns the request id of the response.
+    pub fn request_id(&self) -> Option<&str> {
+        self.meta.as_ref().and_then(ResponseMeta::request_id)
+    }
+
+    /// Consumes the response, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Consumes the response, returning the wrapped value and the metadata.
+    pub fn into_parts(self) -> (T, Option<ResponseMeta>) {
+        (self.value, self.meta)
+    }
+
+    pub(crate) fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response::new(f(self.value), self.meta)
+    }
+}
+
+impl<T> Deref for Response<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Response<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{HeaderMap, HeaderValue};
+    use http::StatusCode;
+
+    use super::ResponseMeta;
+
+    #[test]
+    fn parse_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-modio-request-id", HeaderValue::from_static("abc-123"));
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("60"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("59"));
+
+        let meta = ResponseMeta::new(StatusCode::OK, &headers);
+        assert_eq!(meta.status(), StatusCode::OK);
+        assert_eq!(meta.request_id(), Some("abc-123"));
+        assert_eq!(meta.ratelimit_limit(), Some(60));
+        assert_eq!(meta.ratelimit_remaining(), Some(59));
+        assert_eq!(meta.retry_after(), None);
+        assert_eq!(meta.error_ref(), None);
+    }
+}
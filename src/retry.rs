@@ -0,0 +1,227 @@
+//! Retry policy for failed requests.
+use std::time::Duration;
+
+use http::Method;
+
+use crate::error::Error;
+
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// A `RetryPolicy` defines if and how often failed requests are retried.
+///
+/// Requests are retried when:
+/// - the rate limit has been exhausted. The `retry-after` header of the response is used as delay
+///   if available.
+/// - the request failed with a connection error or a server error (`5xx`) and the
+///   request method is idempotent (`GET`, `PUT`, `DELETE`).
+///
+/// The delay between the attempts grows exponentially, starting at the initial backoff
+/// and capped by the max backoff. Jitter is applied to spread the retries of concurrent requests.
+///
+/// Requests with a streaming body (e.g. file uploads) are never retried.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use modio::retry::RetryPolicy;
+/// use modio::Modio;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let policy = RetryPolicy::new(3)
+///     .initial_backoff(Duration::from_millis(250))
+///     .max_backoff(Duration::from_secs(10));
+///
+/// let modio = Modio::builder("api-key").retry_policy(policy).build()?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Create a new `RetryPolicy` that retries a failed request up to `max_retries` times.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            jitter: true,
+        }
+    }
+
+    /// Create a `RetryPolicy` that never retries a failed request.
+    ///
+    /// This is the default policy.
+    pub fn never() -> Self {
+        Self::new(0)
+    }
+
+    /// Set the delay before the first retry.
+    ///
+    /// Defaults to 500 milliseconds.
+    #[must_use]
+    pub fn initial_backoff(self, initial_backoff: Duration) -> Self {
+        Self {
+            initial_backoff,
+            ..self
+        }
+    }
+
+    /// Set the maximum delay between two attempts.
+    ///
+    /// Defaults to 30 seconds.
+    #[must_use]
+    pub fn max_backoff(self, max_backoff: Duration) -> Self {
+        Self {
+            max_backoff,
+            ..self
+        }
+    }
+
+    /// Enable or disable the randomization of the delay between two attempts.
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn jitter(self, jitter: bool) -> Self {
+        Self { jitter, ..self }
+    }
+
+    /// Returns the maximum number of retries.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the delay before the next attempt or `None` if the request should not be retried.
+    pub(crate) fn retry_delay(
+        &self,
+        attempt: u32,
+        method: &Method,
+        err: &Error,
+    ) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+        if let Some(retry_after) = err.retry_after() {
+            return Some(retry_after);
+        }
+        let retryable = err.is_request() || err.status().map_or(false, |s| s.is_server_error());
+        if retryable && is_idempotent(method) {
+            Some(self.backoff(attempt))
+        } else {
+            None
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .initial_backoff
+            .checked_mul(1 << attempt.min(16))
+            .map_or(self.max_backoff, |d| d.min(self.max_backoff));
+
+        if self.jitter {
+            // "Equal jitter": keep half of the delay and randomize the other half.
+            let half = delay / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            delay
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::never()
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::{Method, StatusCode};
+
+    use super::RetryPolicy;
+    use crate::error;
+    use crate::types::Error as ApiError;
+
+    fn server_error() -> crate::Error {
+        let error = ApiError {
+            code: 503,
+            error_ref: 0,
+            message: String::new(),
+            errors: vec![],
+        };
+        error::error_for_status(StatusCode::SERVICE_UNAVAILABLE, error)
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = RetryPolicy::new(5)
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5))
+            .jitter(false);
+
+        let err = server_error();
+        let delays = (0..6)
+            .map(|n| policy.retry_delay(n, &Method::GET, &err))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            delays,
+            [
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(2)),
+                Some(Duration::from_secs(4)),
+                Some(Duration::from_secs(5)),
+                Some(Duration::from_secs(5)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn jitter_within_bounds() {
+        let policy = RetryPolicy::new(1).initial_backoff(Duration::from_secs(2));
+
+        let err = server_error();
+        for _ in 0..100 {
+            let delay = policy.retry_delay(0, &Method::GET, &err).unwrap();
+            assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn no_retry_for_non_idempotent_methods() {
+        let policy = RetryPolicy::new(3);
+
+        let err = server_error();
+        assert!(policy.retry_delay(0, &Method::POST, &err).is_none());
+
+        let err = error::ratelimit(7);
+        assert_eq!(
+            policy.retry_delay(0, &Method::POST, &err),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn no_retry_for_client_errors() {
+        let policy = RetryPolicy::new(3);
+
+        let err = error::token_required();
+        assert!(policy.retry_delay(0, &Method::GET, &err).is_none());
+    }
+}
@@ -45,6 +45,18 @@ pub enum Route {
         game_id: GameId,
         mod_id: ModId,
     },
+    AddMultipartUploadPart {
+        game_id: GameId,
+        mod_id: ModId,
+    },
+    CompleteMultipartUploadSession {
+        game_id: GameId,
+        mod_id: ModId,
+    },
+    CreateMultipartUploadSession {
+        game_id: GameId,
+        mod_id: ModId,
+    },
     DeleteFile {
         game_id: GameId,
         mod_id: ModId,
@@ -78,6 +90,10 @@ pub enum Route {
         game_id: GameId,
         mod_id: ModId,
     },
+    DeleteMultipartUploadSession {
+        game_id: GameId,
+        mod_id: ModId,
+    },
     EditFile {
         game_id: GameId,
         mod_id: ModId,
@@ -93,13 +109,12 @@ pub enum Route {
         comment_id: CommentId,
     },
     ExternalAuthDiscord,
-    #[allow(dead_code)]
     ExternalAuthEpic,
+    ExternalAuthGalaxy,
     ExternalAuthGoogle,
+    ExternalAuthItchio,
     ExternalAuthMeta,
-    #[allow(dead_code)]
     ExternalAuthOpenID,
-    #[allow(dead_code)]
     ExternalAuthPSN,
     ExternalAuthSteam,
     ExternalAuthSwitch,
@@ -172,6 +187,14 @@ pub enum Route {
     GetModsStats {
         game_id: GameId,
     },
+    GetMultipartUploadParts {
+        game_id: GameId,
+        mod_id: ModId,
+    },
+    GetMultipartUploadSessions {
+        game_id: GameId,
+        mod_id: ModId,
+    },
     ManagePlatformStatus {
         game_id: GameId,
         mod_id: ModId,
@@ -245,6 +268,8 @@ impl Route {
             | Self::GetModStats { .. }
             | Self::GetModTags { .. }
             | Self::GetModTeamMembers { .. }
+            | Self::GetMultipartUploadParts { .. }
+            | Self::GetMultipartUploadSessions { .. }
             | Self::Terms
             | Self::UserAuthenticated
             | Self::UserEvents
@@ -263,9 +288,13 @@ impl Route {
             | Self::AddModMedia { .. }
             | Self::AddModMetadata { .. }
             | Self::AddModTags { .. }
+            | Self::CompleteMultipartUploadSession { .. }
+            | Self::CreateMultipartUploadSession { .. }
             | Self::ExternalAuthDiscord
             | Self::ExternalAuthEpic
+            | Self::ExternalAuthGalaxy
             | Self::ExternalAuthGoogle
+            | Self::ExternalAuthItchio
             | Self::ExternalAuthMeta
             | Self::ExternalAuthOpenID
             | Self::ExternalAuthPSN
@@ -281,7 +310,8 @@ impl Route {
             | Self::SubmitReport { .. }
             | Self::SubscribeToMod { .. }
             | Self::UserMuted => Method::POST,
-            Self::EditMod { .. }
+            Self::AddMultipartUploadPart { .. }
+            | Self::EditMod { .. }
             | Self::EditModComment { .. }
             | Self::EditFile { .. }
             | Self::RenameGameTags { .. }
@@ -294,6 +324,7 @@ impl Route {
             | Self::DeleteModMedia { .. }
             | Self::DeleteModMetadata { .. }
             | Self::DeleteModTags { .. }
+            | Self::DeleteMultipartUploadSession { .. }
             | Self::UnmuteUser { .. }
             | Self::UnsubscribeFromMod { .. } => Method::DELETE,
         }
@@ -303,7 +334,9 @@ impl Route {
         match self {
             Self::ExternalAuthDiscord
             | Self::ExternalAuthEpic
+            | Self::ExternalAuthGalaxy
             | Self::ExternalAuthGoogle
+            | Self::ExternalAuthItchio
             | Self::ExternalAuthMeta
             | Self::ExternalAuthOpenID
             | Self::ExternalAuthPSN
@@ -341,6 +374,9 @@ impl Route {
             | Self::AddModMedia { .. }
             | Self::AddModMetadata { .. }
             | Self::AddModTags { .. }
+            | Self::AddMultipartUploadPart { .. }
+            | Self::CompleteMultipartUploadSession { .. }
+            | Self::CreateMultipartUploadSession { .. }
             | Self::DeleteFile { .. }
             | Self::DeleteGameTags { .. }
             | Self::DeleteMod { .. }
@@ -349,9 +385,12 @@ impl Route {
             | Self::DeleteModMedia { .. }
             | Self::DeleteModMetadata { .. }
             | Self::DeleteModTags { .. }
+            | Self::DeleteMultipartUploadSession { .. }
             | Self::EditFile { .. }
             | Self::EditMod { .. }
             | Self::EditModComment { .. }
+            | Self::GetMultipartUploadParts { .. }
+            | Self::GetMultipartUploadSessions { .. }
             | Self::ManagePlatformStatus { .. }
             | Self::MuteUser { .. }
             | Self::OAuthLogout
@@ -452,6 +491,20 @@ impl fmt::Display for Route {
             | Self::GetModTags { game_id, mod_id } => {
                 path!(f; "/games/", game_id, "/mods/", mod_id, "/tags")
             }
+            Self::AddMultipartUploadPart { game_id, mod_id }
+            | Self::DeleteMultipartUploadSession { game_id, mod_id }
+            | Self::GetMultipartUploadParts { game_id, mod_id } => {
+                path!(f; "/games/", game_id, "/mods/", mod_id, "/files/multipart")
+            }
+            Self::CompleteMultipartUploadSession { game_id, mod_id } => {
+                path!(f; "/games/", game_id, "/mods/", mod_id, "/files/multipart/complete")
+            }
+            Self::CreateMultipartUploadSession { game_id, mod_id } => {
+                path!(f; "/games/", game_id, "/mods/", mod_id, "/files/multipart/create")
+            }
+            Self::GetMultipartUploadSessions { game_id, mod_id } => {
+                path!(f; "/games/", game_id, "/mods/", mod_id, "/files/multipart/sessions")
+            }
             Self::DeleteFile {
                 game_id,
                 mod_id,
@@ -493,7 +546,9 @@ impl fmt::Display for Route {
             }
             Self::ExternalAuthDiscord => f.write_str("/external/discordauth"),
             Self::ExternalAuthEpic => f.write_str("/external/epicgamesauth"),
+            Self::ExternalAuthGalaxy => f.write_str("/external/galaxyauth"),
             Self::ExternalAuthGoogle => f.write_str("/external/googleauth"),
+            Self::ExternalAuthItchio => f.write_str("/external/itchioauth"),
             Self::ExternalAuthMeta => f.write_str("/external/oculusauth"),
             Self::ExternalAuthOpenID => f.write_str("/external/openidauth"),
             Self::ExternalAuthPSN => f.write_str("/external/psnauth"),
@@ -680,6 +735,39 @@ mod tests {
         assert_eq!(route.to_string(), "/games/1/mods/2/tags");
     }
 
+    #[test]
+    fn add_multipart_upload_part() {
+        let route = Route::AddMultipartUploadPart {
+            game_id: GAME_ID,
+            mod_id: MOD_ID,
+        };
+
+        assert_eq!(route.to_string(), "/games/1/mods/2/files/multipart");
+    }
+
+    #[test]
+    fn complete_multipart_upload_session() {
+        let route = Route::CompleteMultipartUploadSession {
+            game_id: GAME_ID,
+            mod_id: MOD_ID,
+        };
+
+        assert_eq!(
+            route.to_string(),
+            "/games/1/mods/2/files/multipart/complete"
+        );
+    }
+
+    #[test]
+    fn create_multipart_upload_session() {
+        let route = Route::CreateMultipartUploadSession {
+            game_id: GAME_ID,
+            mod_id: MOD_ID,
+        };
+
+        assert_eq!(route.to_string(), "/games/1/mods/2/files/multipart/create");
+    }
+
     #[test]
     fn delete_file() {
         let route = Route::DeleteFile {
@@ -759,6 +847,16 @@ mod tests {
         assert_eq!(route.to_string(), "/games/1/mods/2/tags");
     }
 
+    #[test]
+    fn delete_multipart_upload_session() {
+        let route = Route::DeleteMultipartUploadSession {
+            game_id: GAME_ID,
+            mod_id: MOD_ID,
+        };
+
+        assert_eq!(route.to_string(), "/games/1/mods/2/files/multipart");
+    }
+
     #[test]
     fn edit_file() {
         let route = Route::EditFile {
@@ -805,6 +903,13 @@ mod tests {
         assert_eq!(route.to_string(), "/external/epicgamesauth");
     }
 
+    #[test]
+    fn external_auth_galaxy() {
+        let route = Route::ExternalAuthGalaxy;
+
+        assert_eq!(route.to_string(), "/external/galaxyauth");
+    }
+
     #[test]
     fn external_auth_google() {
         let route = Route::ExternalAuthGoogle;
@@ -812,6 +917,13 @@ mod tests {
         assert_eq!(route.to_string(), "/external/googleauth");
     }
 
+    #[test]
+    fn external_auth_itchio() {
+        let route = Route::ExternalAuthItchio;
+
+        assert_eq!(route.to_string(), "/external/itchioauth");
+    }
+
     #[test]
     fn external_auth_meta() {
         let route = Route::ExternalAuthMeta;
@@ -1033,6 +1145,29 @@ mod tests {
         assert_eq!(route.to_string(), "/games/1/mods/stats");
     }
 
+    #[test]
+    fn get_multipart_upload_parts() {
+        let route = Route::GetMultipartUploadParts {
+            game_id: GAME_ID,
+            mod_id: MOD_ID,
+        };
+
+        assert_eq!(route.to_string(), "/games/1/mods/2/files/multipart");
+    }
+
+    #[test]
+    fn get_multipart_upload_sessions() {
+        let route = Route::GetMultipartUploadSessions {
+            game_id: GAME_ID,
+            mod_id: MOD_ID,
+        };
+
+        assert_eq!(
+            route.to_string(),
+            "/games/1/mods/2/files/multipart/sessions"
+        );
+    }
+
     #[test]
     fn manage_platform_status() {
         let route = Route::ManagePlatformStatus {
@@ -0,0 +1,227 @@
+//! Incremental synchronization of subscriptions.
+//!
+//! [`SubscriptionSync`] polls the user events for subscription changes and the mod events of the
+//! game for changed modfiles. Each poll returns a [`SyncDiff`] of the mods to install, update or
+//! remove. The event cursors and the known subscriptions are kept in a [`SyncState`] that can be
+//! persisted between runs.
+//!
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use modio::sync::{SubscriptionSync, SyncState};
+//! use modio::types::id::Id;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #     let modio = modio::Modio::new(("api-key", "token"))?;
+//! let state = SyncState::load("sync.json").await?;
+//! let mut sync = SubscriptionSync::new(modio, Id::new(51), state);
+//!
+//! loop {
+//!     let diff = sync.poll().await?;
+//!     println!("install: {:?}", diff.install);
+//!     println!("update: {:?}", diff.update);
+//!     println!("remove: {:?}", diff.remove);
+//!
+//!     sync.state().save("sync.json").await?;
+//!     tokio::time::sleep(Duration::from_secs(60)).await;
+//! }
+//! # }
+//! ```
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::events::Cursor;
+use crate::filter::prelude::*;
+use crate::fs;
+use crate::mods::filters::events::EventType as ModEventTypeFilter;
+use crate::prelude::*;
+use crate::types::id::{GameId, ModId};
+use crate::types::mods::EventType as ModEventType;
+use crate::types::EventType as UserEventType;
+use crate::user::filters::events::GameId as GameIdFilter;
+
+/// Persistable state of a [`SubscriptionSync`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SyncState {
+    initialized: bool,
+    user_events: Cursor,
+    mod_events: Cursor,
+    subscriptions: BTreeSet<ModId>,
+}
+
+impl SyncState {
+    /// Load the state from a JSON file. A missing file returns the initial state.
+    pub async fn load<P: AsRef<Path>>(path: P) -> Result<SyncState> {
+        let state = fs::read_json(path.as_ref()).await?;
+        Ok(state.unwrap_or_default())
+    }
+
+    /// Save the state as a JSON file.
+    ///
+    /// The state is written to a temporary file that replaces the file, an interrupted save
+    /// keeps the previous state.
+    pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::write_json_atomic(path.as_ref(), self).await
+    }
+
+    /// Returns the cursor of the user events.
+    pub fn user_events(&self) -> Cursor {
+        self.user_events
+    }
+
+    /// Returns the cursor of the mod events.
+    pub fn mod_events(&self) -> Cursor {
+        self.mod_events
+    }
+
+    /// Returns the known subscriptions.
+    pub fn subscriptions(&self) -> &BTreeSet<ModId> {
+        &self.subscriptions
+    }
+}
+
+/// Changes of the subscriptions since the last poll.
+#[derive(Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SyncDiff {
+    /// Newly subscribed mods.
+    pub install: Vec<ModId>,
+    /// Subscribed mods with a new primary modfile.
+    pub update: Vec<ModId>,
+    /// Unsubscribed mods.
+    pub remove: Vec<ModId>,
+}
+
+impl SyncDiff {
+    /// Returns `true` if nothing has changed.
+    pub fn is_empty(&self) -> bool {
+        self.install.is_empty() && self.update.is_empty() && self.remove.is_empty()
+    }
+}
+
+/// Computes the changes of the subscriptions of a game from the event logs.
+///
+/// The first poll with an initial state returns all current subscriptions as `install`.
+/// See the [module documentation](self) for an example.
+pub struct SubscriptionSync {
+    modio: Modio,
+    game_id: GameId,
+    state: SyncState,
+}
+
+impl SubscriptionSync {
+    pub fn new(modio: Modio, game_id: GameId, state: SyncState) -> Self {
+        Self {
+            modio,
+            game_id,
+            state,
+        }
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> &SyncState {
+        &self.state
+    }
+
+    /// Consume the `SubscriptionSync` and return the current state.
+    pub fn into_state(self) -> SyncState {
+        self.state
+    }
+
+    /// Poll the event logs and return the changes since the last poll. [required: token]
+    ///
+    /// The state is only updated if all requests succeed.
+    pub async fn poll(&mut self) -> Result<SyncDiff> {
+        if !self.state.initialized {
+            return self.initialize().await;
+        }
+        let mut state = self.state.clone();
+        let mut diff = SyncDiff::default();
+
+        // The latest subscription event of a mod wins.
+        let filter = GameIdFilter::eq(self.game_id)
+            .and(state.user_events.filter())
+            .order_by(Id::asc());
+        let events = self.modio.user().events(filter).collect().await?;
+        let mut changes = BTreeMap::new();
+        for event in events {
+            state.user_events.advance(event.id, event.date_added);
+            if event.event_type == UserEventType::USER_SUBSCRIBE {
+                changes.insert(event.mod_id, true);
+            } else if event.event_type == UserEventType::USER_UNSUBSCRIBE {
+                changes.insert(event.mod_id, false);
+            }
+        }
+        for (mod_id, subscribed) in changes {
+            match (subscribed, state.subscriptions.contains(&mod_id)) {
+                (true, false) => {
+                    state.subscriptions.insert(mod_id);
+                    diff.install.push(mod_id);
+                }
+                (false, true) => {
+                    state.subscriptions.remove(&mod_id);
+                    diff.remove.push(mod_id);
+                }
+                _ => {}
+            }
+        }
+
+        let filter = ModEventTypeFilter::eq(ModEventType::MODFILE_CHANGED)
+            .and(state.mod_events.filter())
+            .order_by(Id::asc());
+        let events = self
+            .modio
+            .game(self.game_id)
+            .mods()
+            .events(filter)
+            .collect()
+            .await?;
+        for event in events {
+            state.mod_events.advance(event.id, event.date_added);
+            let mod_id = event.mod_id;
+            if state.subscriptions.contains(&mod_id)
+                && !diff.install.contains(&mod_id)
+                && !diff.update.contains(&mod_id)
+            {
+                diff.update.push(mod_id);
+            }
+        }
+
+        self.state = state;
+        Ok(diff)
+    }
+
+    /// Fetch the current subscriptions and set the cursors to the latest events.
+    async fn initialize(&mut self) -> Result<SyncDiff> {
+        let mut state = SyncState {
+            initialized: true,
+            ..Default::default()
+        };
+
+        let filter = GameIdFilter::eq(self.game_id).order_by(Id::desc());
+        state.user_events = match self.modio.user().events(filter).first().await? {
+            Some(event) => Cursor::new(event.id, event.date_added),
+            None => Cursor::now(),
+        };
+        let mods = self.modio.game(self.game_id).mods();
+        state.mod_events = match mods.events(Id::desc()).first().await? {
+            Some(event) => Cursor::new(event.id, event.date_added),
+            None => Cursor::now(),
+        };
+
+        let filter = crate::mods::filters::GameId::eq(self.game_id);
+        let mods = self.modio.user().subscriptions(filter).collect().await?;
+        state.subscriptions = mods.iter().map(|m| m.id).collect();
+
+        let diff = SyncDiff {
+            install: state.subscriptions.iter().copied().collect(),
+            ..Default::default()
+        };
+        self.state = state;
+        Ok(diff)
+    }
+}
@@ -0,0 +1,283 @@
+//! Record and replay of HTTP exchanges.
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use http::header::{HeaderMap, AUTHORIZATION, CONTENT_LENGTH, SET_COOKIE, TRANSFER_ENCODING};
+use http::Uri;
+use serde::{Deserialize, Serialize};
+
+use crate::transport::{Body, BoxError, Request, Response, Transport};
+
+const REDACTED: &str = "REDACTED";
+
+/// Record or replay mode of a client set with `Builder::record` or `Builder::replay`.
+pub(crate) enum Mode {
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+impl Mode {
+    /// Wrap or replace the transport of the client.
+    pub(crate) fn apply(
+        self,
+        transport: Arc<dyn Transport>,
+    ) -> std::io::Result<Arc<dyn Transport>> {
+        Ok(match self {
+            Self::Record(path) => Arc::new(Recorder::new(path, transport)),
+            Self::Replay(path) => Arc::new(Player::load(&path)?),
+        })
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+struct RecordedRequest {
+    method: String,
+    uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: RecordedBody,
+}
+
+/// Text bodies are stored as is, binary bodies are base64 encoded.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordedBody {
+    Text(String),
+    Base64(String),
+}
+
+impl RecordedBody {
+    fn new(data: &[u8], secrets: &[String]) -> Self {
+        match std::str::from_utf8(data) {
+            Ok(text) => Self::Text(scrub(text, secrets)),
+            Err(_) => Self::Base64(STANDARD.encode(data)),
+        }
+    }
+
+    fn into_bytes(self) -> Result<Bytes, BoxError> {
+        match self {
+            Self::Text(text) => Ok(Bytes::from(text)),
+            Self::Base64(data) => Ok(Bytes::from(STANDARD.decode(data)?)),
+        }
+    }
+}
+
+/// Returns the api key and the bearer token of the request.
+fn secrets(uri: &Uri, headers: &HeaderMap) -> Vec<String> {
+    let api_key = uri.query().into_iter().flat_map(|q| {
+        url::form_urlencoded::parse(q.as_bytes())
+            .filter(|(k, _)| k == "api_key")
+            .map(|(_, v)| v.into_owned())
+            .collect::<Vec<_>>()
+    });
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(ToOwned::to_owned);
+
+    api_key.chain(token).filter(|s| !s.is_empty()).collect()
+}
+
+fn scrub(text: &str, secrets: &[String]) -> String {
+    secrets.iter().fold(text.to_owned(), |text, secret| {
+        text.replace(secret, REDACTED)
+    })
+}
+
+/// Sends the requests with the inner transport and writes every exchange to the cassette file.
+struct Recorder {
+    path: PathBuf,
+    inner: Arc<dyn Transport>,
+    cassette: Arc<tokio::sync::Mutex<Cassette>>,
+}
+
+impl Recorder {
+    fn new(path: PathBuf, inner: Arc<dyn Transport>) -> Self {
+        Self {
+            path,
+            inner,
+            cassette: Default::default(),
+        }
+    }
+}
+
+impl Transport for Recorder {
+    fn execute(&self, request: Request) -> BoxFuture<'static, Result<Response, BoxError>> {
+        let path = self.path.clone();
+        let inner = Arc::clone(&self.inner);
+        let cassette = Arc::clone(&self.cassette);
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let mut secrets = secrets(&parts.uri, &parts.headers);
+            let body = body.bytes().await?;
+
+            let recorded = RecordedRequest {
+                method: parts.method.to_string(),
+                uri: scrub(&parts.uri.to_string(), &secrets),
+                body: (!body.is_empty()).then(|| RecordedBody::new(&body, &secrets)),
+            };
+
+            let request = http::Request::from_parts(parts, Body::from(body));
+            let (parts, body) = inner.execute(request).await?.into_parts();
+            let body = body.bytes().await?;
+
+            // Access tokens issued by the authentication endpoints.
+            if let Ok(serde_json::Value::Object(obj)) = serde_json::from_slice(&body) {
+                if let Some(serde_json::Value::String(token)) = obj.get("access_token") {
+                    secrets.push(token.clone());
+                }
+            }
+
+            let interaction = Interaction {
+                request: recorded,
+                response: RecordedResponse {
+                    status: parts.status.as_u16(),
+                    headers: recorded_headers(&parts.headers, &secrets),
+                    body: RecordedBody::new(&body, &secrets),
+                },
+            };
+
+            let mut cassette = cassette.lock().await;
+            cassette.interactions.push(interaction);
+            let data = serde_json::to_vec_pretty(&*cassette)?;
+            let tmp = path.with_extension("tmp");
+            tokio::fs::write(&tmp, data).await?;
+            tokio::fs::rename(&tmp, &path).await?;
+
+            Ok(http::Response::from_parts(parts, Body::from(body)))
+        })
+    }
+}
+
+fn recorded_headers(headers: &HeaderMap, secrets: &[String]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| ![CONTENT_LENGTH, SET_COOKIE, TRANSFER_ENCODING].contains(name))
+        .filter_map(|(name, value)| {
+            let value = value.to_str().ok()?;
+            Some((name.to_string(), scrub(value, secrets)))
+        })
+        .collect()
+}
+
+/// Serves the responses from a cassette file.
+///
+/// Requests are matched by method and uri. Identical requests are answered in the recorded order
+/// and the last matching response is repeated once all of them are used.
+struct Player {
+    interactions: Vec<Interaction>,
+    used: Mutex<Vec<bool>>,
+}
+
+impl Player {
+    fn load(path: &Path) -> std::io::Result<Self> {
+        let data = std::fs::read(path)?;
+        let cassette: Cassette = serde_json::from_slice(&data)?;
+        let used = vec![false; cassette.interactions.len()];
+        Ok(Self {
+            interactions: cassette.interactions,
+            used: Mutex::new(used),
+        })
+    }
+
+    fn find(&self, method: &str, uri: &str) -> Option<Interaction> {
+        let mut used = self.used.lock().expect("cassette state poisoned");
+        let matching = self
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.request.method == method && i.request.uri == uri)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+
+        let pos = matching
+            .iter()
+            .copied()
+            .find(|pos| !used[*pos])
+            .or_else(|| matching.last().copied())?;
+        used[pos] = true;
+        Some(self.interactions[pos].clone())
+    }
+}
+
+impl Transport for Player {
+    fn execute(&self, request: Request) -> BoxFuture<'static, Result<Response, BoxError>> {
+        let secrets = secrets(request.uri(), request.headers());
+        let method = request.method().to_string();
+        let uri = scrub(&request.uri().to_string(), &secrets);
+        let interaction = self.find(&method, &uri);
+
+        Box::pin(async move {
+            let response = match interaction {
+                Some(interaction) => interaction.response,
+                None => {
+                    let msg = format!("no recorded response for {method} {uri}");
+                    return Err(msg.into());
+                }
+            };
+
+            let mut builder = http::Response::builder().status(response.status);
+            for (name, value) in &response.headers {
+                builder = builder.header(name, value);
+            }
+            Ok(builder.body(Body::from(response.body.into_bytes()?))?)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+    use http::Uri;
+
+    use super::{scrub, secrets, RecordedBody};
+
+    #[test]
+    fn scrub_secrets() {
+        let uri: Uri = "https://api.mod.io/v1/games?api_key=foobar&_limit=10"
+            .parse()
+            .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer token"));
+
+        let secrets = secrets(&uri, &headers);
+        assert_eq!(secrets, ["foobar", "token"]);
+        assert_eq!(
+            scrub(&uri.to_string(), &secrets),
+            "https://api.mod.io/v1/games?api_key=REDACTED&_limit=10"
+        );
+    }
+
+    #[test]
+    fn body_encoding() {
+        let body = RecordedBody::new(b"token=abc", &["abc".to_owned()]);
+        assert!(matches!(body, RecordedBody::Text(ref s) if s == "token=REDACTED"));
+
+        let data = [0xff, 0x00, 0xfe];
+        let body = RecordedBody::new(&data, &[]);
+        assert!(matches!(body, RecordedBody::Base64(_)));
+        assert_eq!(&body.into_bytes().unwrap()[..], data);
+    }
+}
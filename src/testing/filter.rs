@@ -0,0 +1,243 @@
+//! Evaluation of the filter, sorting and pagination parameters of list requests.
+use std::cmp::Ordering;
+
+use serde_json::{json, Value};
+
+const DEFAULT_LIMIT: usize = 100;
+
+/// Operator suffixes of the filter parameters. The order matters because `-not` is a prefix of
+/// `-not-lk` and `-not-in`.
+const OPERATORS: &[&str] = &[
+    "-not-lk",
+    "-not-in",
+    "-bitwise-and",
+    "-not",
+    "-lk",
+    "-in",
+    "-min",
+    "-max",
+    "-st",
+    "-gt",
+];
+
+/// Filters, sorts and paginates the items and returns a list response object.
+pub fn apply(items: Vec<Value>, query: &[(String, String)]) -> Value {
+    let mut limit = DEFAULT_LIMIT;
+    let mut offset = 0;
+    let mut sort = None;
+    let mut conditions = Vec::new();
+
+    for (key, value) in query {
+        match key.as_str() {
+            "api_key" => {}
+            "_limit" => limit = value.parse().unwrap_or(DEFAULT_LIMIT).min(DEFAULT_LIMIT),
+            "_offset" => offset = value.parse().unwrap_or_default(),
+            "_sort" => sort = Some(value.as_str()),
+            "_q" => conditions.push(("_q", "", value.as_str())),
+            _ => {
+                let (field, op) = OPERATORS
+                    .iter()
+                    .find_map(|op| key.strip_suffix(op).map(|field| (field, *op)))
+                    .unwrap_or((key.as_str(), ""));
+                conditions.push((field, op, value.as_str()));
+            }
+        }
+    }
+
+    let mut items = items
+        .into_iter()
+        .filter(|item| {
+            conditions
+                .iter()
+                .all(|(field, op, value)| matches(item, field, op, value))
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(sort) = sort {
+        let (field, desc) = match sort.strip_prefix('-') {
+            Some(field) => (field, true),
+            None => (sort, false),
+        };
+        items.sort_by(|a, b| {
+            let ord = compare(&values(a, field), &values(b, field));
+            if desc {
+                ord.reverse()
+            } else {
+                ord
+            }
+        });
+    }
+
+    let total = items.len();
+    let data = items
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .collect::<Vec<_>>();
+
+    json!({
+        "result_count": data.len(),
+        "result_offset": offset,
+        "result_limit": limit,
+        "result_total": total,
+        "data": data,
+    })
+}
+
+fn matches(item: &Value, field: &str, op: &str, value: &str) -> bool {
+    if field == "_q" {
+        let value = value.to_lowercase();
+        return ["name", "summary"]
+            .iter()
+            .flat_map(|field| values(item, field))
+            .any(|v| v.to_lowercase().contains(&value));
+    }
+
+    let values = values(item, field);
+    let any = |f: &dyn Fn(&str) -> bool| values.iter().any(|v| f(v));
+    let list = || value.split(',').map(str::trim);
+
+    match op {
+        "" => any(&|v| v.eq_ignore_ascii_case(value)),
+        "-not" => !any(&|v| v.eq_ignore_ascii_case(value)),
+        "-lk" => any(&|v| like(value, v)),
+        "-not-lk" => !any(&|v| like(value, v)),
+        "-in" => any(&|v| list().any(|x| v.eq_ignore_ascii_case(x))),
+        "-not-in" => !any(&|v| list().any(|x| v.eq_ignore_ascii_case(x))),
+        "-min" => any(&|v| number_cmp(v, value).map_or(false, Ordering::is_ge)),
+        "-max" => any(&|v| number_cmp(v, value).map_or(false, Ordering::is_le)),
+        "-st" => any(&|v| number_cmp(v, value).map_or(false, Ordering::is_lt)),
+        "-gt" => any(&|v| number_cmp(v, value).map_or(false, Ordering::is_gt)),
+        "-bitwise-and" => {
+            let bits = value.parse::<u64>().unwrap_or_default();
+            any(&|v| v.parse::<u64>().map_or(false, |v| v & bits != 0))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the values of a field as strings.
+///
+/// Arrays return a value for each element and objects are represented by their `id` or `name`
+/// property, e.g. `submitted_by` by the user id and `tags` by the tag names.
+fn values(item: &Value, field: &str) -> Vec<String> {
+    fn scalar(value: &Value) -> Option<String> {
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            Value::Bool(b) => Some(u8::from(*b).to_string()),
+            Value::Object(o) => o.get("id").or_else(|| o.get("name")).and_then(scalar),
+            Value::Null | Value::Array(_) => None,
+        }
+    }
+
+    match item.get(field) {
+        Some(Value::Array(values)) => values.iter().filter_map(scalar).collect(),
+        Some(value) => scalar(value).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+fn number_cmp(a: &str, b: &str) -> Option<Ordering> {
+    let a = a.parse::<f64>().ok()?;
+    let b = b.parse::<f64>().ok()?;
+    a.partial_cmp(&b)
+}
+
+fn compare(a: &[String], b: &[String]) -> Ordering {
+    match (a.first(), b.first()) {
+        (Some(a), Some(b)) => number_cmp(a, b).unwrap_or_else(|| a.cmp(b)),
+        (a, b) => a.cmp(&b),
+    }
+}
+
+/// Case-insensitive matching with `*` as wildcard.
+fn like(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let value = value.to_lowercase();
+    let mut parts = pattern.split('*');
+
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match value.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let mut parts = parts.collect::<Vec<_>>();
+    let last = match parts.pop() {
+        Some(last) => last,
+        None => return rest.is_empty(),
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{apply, like};
+
+    fn query(params: &[(&str, &str)]) -> Vec<(String, String)> {
+        params
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect()
+    }
+
+    fn ids(list: &serde_json::Value) -> Vec<u64> {
+        list["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["id"].as_u64().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn like_patterns() {
+        assert!(like("foo", "FOO"));
+        assert!(like("foo*", "foobar"));
+        assert!(like("*bar", "foobar"));
+        assert!(like("f*o*r", "foobar"));
+        assert!(like("*", "anything"));
+        assert!(!like("foo", "foobar"));
+        assert!(!like("*baz*", "foobar"));
+    }
+
+    #[test]
+    fn filter_sort_paginate() {
+        let items = vec![
+            json!({"id": 1, "name": "Alpha", "tags": [{"name": "Maps"}], "submitted_by": 7}),
+            json!({"id": 2, "name": "Beta", "tags": [{"name": "Skins"}], "submitted_by": 8}),
+            json!({"id": 3, "name": "Gamma", "tags": [{"name": "Maps"}], "submitted_by": 7}),
+        ];
+
+        let list = apply(items.clone(), &query(&[("tags", "maps")]));
+        assert_eq!(ids(&list), [1, 3]);
+
+        let list = apply(items.clone(), &query(&[("id-not-in", "1,2")]));
+        assert_eq!(ids(&list), [3]);
+
+        let list = apply(
+            items.clone(),
+            &query(&[("submitted_by", "7"), ("_sort", "-id")]),
+        );
+        assert_eq!(ids(&list), [3, 1]);
+
+        let list = apply(items.clone(), &query(&[("name-lk", "*a"), ("id-gt", "1")]));
+        assert_eq!(ids(&list), [2, 3]);
+
+        let list = apply(items.clone(), &query(&[("_q", "amm")]));
+        assert_eq!(ids(&list), [3]);
+
+        let list = apply(items, &query(&[("_limit", "1"), ("_offset", "1")]));
+        assert_eq!(ids(&list), [2]);
+        assert_eq!(list["result_total"], 3);
+        assert_eq!(list["result_count"], 1);
+    }
+}
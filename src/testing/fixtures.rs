@@ -0,0 +1,210 @@
+//! Default JSON objects used for the seeded data of the mock server.
+use serde_json::{json, Value};
+
+fn logo(name: &str) -> Value {
+    let url = format!("https://mock.modio.test/images/{name}.png");
+    json!({
+        "filename": format!("{name}.png"),
+        "original": url,
+        "thumb_320x180": url,
+        "thumb_640x360": url,
+        "thumb_1280x720": url,
+    })
+}
+
+pub fn game(id: u64) -> Value {
+    let icon = format!("https://mock.modio.test/images/game-{id}-icon.png");
+    json!({
+        "id": id,
+        "status": 1,
+        "submitted_by": {},
+        "date_added": 0,
+        "date_updated": 0,
+        "date_live": 0,
+        "presentation_option": 0,
+        "community_options": 0,
+        "monetisation_options": 0,
+        "submission_option": 1,
+        "curation_option": 0,
+        "revenue_options": 0,
+        "api_access_options": 3,
+        "maturity_options": 0,
+        "ugc_name": "Mods",
+        "icon": {
+            "filename": format!("game-{id}-icon.png"),
+            "original": icon,
+            "thumb_64x64": icon,
+            "thumb_128x128": icon,
+            "thumb_256x256": icon,
+        },
+        "logo": logo(&format!("game-{id}")),
+        "header": {},
+        "name": format!("Game {id}"),
+        "name_id": format!("game-{id}"),
+        "summary": "",
+        "instructions": null,
+        "instructions_url": null,
+        "profile_url": format!("https://mod.io/g/game-{id}"),
+        "other_urls": [],
+        "tag_options": [],
+        "stats": {},
+        "theme": {},
+        "platforms": [],
+    })
+}
+
+pub fn user(id: u64) -> Value {
+    json!({
+        "id": id,
+        "name_id": format!("user-{id}"),
+        "username": format!("user-{id}"),
+        "date_online": 0,
+        "avatar": {},
+        "profile_url": format!("https://mod.io/u/user-{id}"),
+    })
+}
+
+pub fn mod_(game_id: u64, id: u64) -> Value {
+    json!({
+        "id": id,
+        "game_id": game_id,
+        "status": 1,
+        "visible": 1,
+        "submitted_by": user(1),
+        "date_added": 0,
+        "date_updated": 0,
+        "date_live": 0,
+        "maturity_option": 0,
+        "community_options": 0,
+        "price": 0,
+        "tax": 0,
+        "logo": logo(&format!("mod-{id}")),
+        "homepage_url": null,
+        "name": format!("Mod {id}"),
+        "name_id": format!("mod-{id}"),
+        "summary": "",
+        "description": null,
+        "description_plaintext": null,
+        "metadata_blob": null,
+        "profile_url": format!("https://mod.io/g/game-{game_id}/m/mod-{id}"),
+        "modfile": null,
+        "media": {},
+        "metadata_kvp": [],
+        "tags": [],
+        "dependencies": false,
+        "stats": {
+            "mod_id": id,
+            "downloads_today": 0,
+            "downloads_total": 0,
+            "subscribers_total": 0,
+            "popularity_rank_position": 0,
+            "popularity_rank_total_mods": 0,
+            "ratings_total": 0,
+            "ratings_positive": 0,
+            "ratings_negative": 0,
+            "ratings_percentage_positive": 0,
+            "ratings_weighted_aggregate": 0,
+            "ratings_display_text": "",
+            "date_expires": 0,
+        },
+        "platforms": [],
+    })
+}
+
+pub fn file(mod_id: u64, id: u64) -> Value {
+    json!({
+        "id": id,
+        "mod_id": mod_id,
+        "date_added": 0,
+        "date_scanned": 0,
+        "virus_status": 1,
+        "virus_positive": 0,
+        "filesize": 0,
+        "filesize_uncompressed": 0,
+        "filehash": {"md5": "d41d8cd98f00b204e9800998ecf8427e"},
+        "filename": format!("file-{id}.zip"),
+        "version": null,
+        "changelog": null,
+        "metadata_blob": null,
+        "download": {
+            "binary_url": format!("https://mock.modio.test/files/{id}/file-{id}.zip"),
+            "date_expires": 0,
+        },
+        "platforms": [],
+    })
+}
+
+pub fn comment(mod_id: u64, id: u64) -> Value {
+    // `Comment::reply_id` doesn't accept `0`, top-level comments refer to themselves instead.
+    json!({
+        "id": id,
+        "resource_id": mod_id,
+        "user": user(1),
+        "date_added": 0,
+        "reply_id": id,
+        "thread_position": "01",
+        "karma": 0,
+        "content": "",
+    })
+}
+
+pub fn event(mod_id: u64, id: u64) -> Value {
+    json!({
+        "id": id,
+        "mod_id": mod_id,
+        "user_id": 1,
+        "date_added": 0,
+        "event_type": "MOD_EDITED",
+    })
+}
+
+pub fn user_event(game_id: u64, mod_id: u64, user_id: u64, id: u64) -> Value {
+    json!({
+        "id": id,
+        "game_id": game_id,
+        "mod_id": mod_id,
+        "user_id": user_id,
+        "date_added": 0,
+        "event_type": "USER_SUBSCRIBE",
+    })
+}
+
+pub fn terms() -> Value {
+    let link = |text: &str, url: &str, required: bool| {
+        json!({
+            "text": text,
+            "url": url,
+            "required": required,
+        })
+    };
+    json!({
+        "plaintext": "By using this service you agree to the mod.io Terms of Use.",
+        "html": "<p>By using this service you agree to the mod.io Terms of Use.</p>",
+        "links": {
+            "website": link("Website", "https://mod.io", false),
+            "terms": link("Terms of Use", "https://mod.io/terms", true),
+            "privacy": link("Privacy Policy", "https://mod.io/privacy", true),
+            "manage": link("Manage Account", "https://mod.io/me/account", false),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::auth::Terms;
+    use crate::types::files::File;
+    use crate::types::games::Game;
+    use crate::types::mods::{Comment, Event, Mod};
+    use crate::types::User;
+
+    #[test]
+    fn deserialize_fixtures() {
+        serde_json::from_value::<Game>(super::game(1)).unwrap();
+        serde_json::from_value::<User>(super::user(1)).unwrap();
+        serde_json::from_value::<Mod>(super::mod_(1, 2)).unwrap();
+        serde_json::from_value::<File>(super::file(2, 3)).unwrap();
+        serde_json::from_value::<Comment>(super::comment(2, 4)).unwrap();
+        serde_json::from_value::<Event>(super::event(2, 5)).unwrap();
+        serde_json::from_value::<Terms>(super::terms()).unwrap();
+    }
+}
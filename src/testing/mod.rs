@@ -0,0 +1,629 @@
+//! In-process fake mod.io server for integration tests.
+//!
+//! [`MockServer`] is a [`Transport`] that answers the requests of a client from in-memory state
+//! without any network access. The server is seeded with JSON objects which are merged into
+//! complete default objects, so only the properties relevant to a test need to be specified.
+//!
+//! The following endpoints are served:
+//!
+//! - Games: `GET /games`, `GET /games/{id}` and `GET /games/{id}/tags`
+//! - Mods: `GET /games/{id}/mods` and `GET /games/{id}/mods/{id}`
+//! - Files: `GET /games/{id}/mods/{id}/files` and `GET /games/{id}/mods/{id}/files/{id}`
+//! - Comments: listing, retrieving and adding comments of a mod
+//! - Events: `GET /games/{id}/mods/events`, `GET /games/{id}/mods/{id}/events` and
+//!   `GET /me/events`
+//! - Subscriptions: subscribing, unsubscribing and `GET /me/subscribed`. Subscribing and
+//!   unsubscribing add a `USER_SUBSCRIBE` or `USER_UNSUBSCRIBE` user event.
+//! - Authentication: terms, email request & exchange, external authentication, logout and `GET /me`
+//!
+//! List endpoints honor the [`Filter`](crate::filter::Filter) parameters, sorting and pagination.
+//! Unknown endpoints respond with `404 Not Found`.
+//!
+//! Exchanges with the real mod.io API can be recorded to a cassette file with
+//! [`Builder::record`] and served in tests without network access with [`Builder::replay`].
+//!
+//! # Example
+//! ```no_run
+//! use modio::filter::prelude::*;
+//! use modio::mods::filters::Name;
+//! use modio::testing::MockServer;
+//! use modio::types::id::Id;
+//! use serde_json::json;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = MockServer::new();
+//! let game_id = server.add_game(json!({"name": "Example"}));
+//! server.add_mod(game_id, json!({"name": "Foo"}));
+//! server.add_mod(game_id, json!({"name": "Bar"}));
+//!
+//! let modio = server.client()?;
+//! let mods = modio
+//!     .game(game_id)
+//!     .mods()
+//!     .search(Name::eq("Foo"))
+//!     .collect()
+//!     .await?;
+//! assert_eq!(mods.len(), 1);
+//! #     Ok(())
+//! # }
+//! ```
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures_util::future::BoxFuture;
+use http::header::{AUTHORIZATION, CONTENT_TYPE};
+use http::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use crate::auth::Credentials;
+use crate::error::Result;
+use crate::transport::{Body, BoxError, Request, Response, Transport};
+use crate::types::files::File;
+use crate::types::games::Game;
+use crate::types::id::{CommentId, EventId, FileId, GameId, Id, ModId, UserId};
+use crate::types::mods::{Comment, Event, Mod};
+use crate::types::User;
+use crate::{Builder, Modio};
+
+pub(crate) mod cassette;
+mod filter;
+mod fixtures;
+
+/// The API key used by [`MockServer::client`].
+pub const API_KEY: &str = "mock-api-key";
+
+const HOST: &str = "https://mock.modio.test/v1";
+const TOKEN_LIFETIME: u64 = 365 * 24 * 60 * 60;
+
+/// In-process fake mod.io server.
+///
+/// Cloned servers share the same state.
+#[derive(Clone, Default)]
+pub struct MockServer {
+    state: Arc<Mutex<State>>,
+    request_ids: Arc<AtomicU64>,
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    games: Vec<Value>,
+    mods: Vec<Value>,
+    files: Vec<Value>,
+    comments: Vec<Value>,
+    events: Vec<Value>,
+    user_events: Vec<Value>,
+    users: Vec<Value>,
+    tokens: HashMap<String, u64>,
+    emails: HashMap<String, u64>,
+    security_codes: HashMap<String, String>,
+    subscriptions: BTreeSet<(u64, u64)>,
+}
+
+impl MockServer {
+    /// Create a new server without any data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a client builder using the mock server as transport.
+    pub fn builder<C: Into<Credentials>>(&self, credentials: C) -> Builder {
+        Modio::builder(credentials)
+            .host(HOST)
+            .transport(self.clone())
+    }
+
+    /// Create a client with the [`API_KEY`] using the mock server as transport.
+    pub fn client(&self) -> Result<Modio> {
+        self.builder(API_KEY).build()
+    }
+
+    /// Add a game and return its id.
+    ///
+    /// The id is generated if the object has no `id` property.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the merged object is not a valid [`Game`].
+    pub fn add_game(&self, game: Value) -> GameId {
+        let mut state = self.lock();
+        let id = state.id(&game);
+        let game = validate::<Game>("game", merge(fixtures::game(id), game));
+        state.games.push(game);
+        Id::new(id)
+    }
+
+    /// Add a mod to a game and return its id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the merged object is not a valid [`Mod`].
+    pub fn add_mod(&self, game_id: GameId, mod_: Value) -> ModId {
+        let mut state = self.lock();
+        let id = state.id(&mod_);
+        let mut mod_ = merge(fixtures::mod_(game_id.get(), id), mod_);
+        mod_["game_id"] = json!(game_id);
+        mod_["stats"]["mod_id"] = json!(id);
+        state.mods.push(validate::<Mod>("mod", mod_));
+        Id::new(id)
+    }
+
+    /// Add a modfile to a mod and return its id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the merged object is not a valid [`File`].
+    pub fn add_file(&self, mod_id: ModId, file: Value) -> FileId {
+        let mut state = self.lock();
+        let id = state.id(&file);
+        let mut file = merge(fixtures::file(mod_id.get(), id), file);
+        file["mod_id"] = json!(mod_id);
+        state.files.push(validate::<File>("file", file));
+        Id::new(id)
+    }
+
+    /// Add a comment to a mod and return its id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the merged object is not a valid [`Comment`].
+    pub fn add_comment(&self, mod_id: ModId, comment: Value) -> CommentId {
+        let mut state = self.lock();
+        let id = state.id(&comment);
+        let mut comment = merge(fixtures::comment(mod_id.get(), id), comment);
+        comment["resource_id"] = json!(mod_id);
+        state.comments.push(validate::<Comment>("comment", comment));
+        Id::new(id)
+    }
+
+    /// Add a mod event and return its id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the merged object is not a valid [`Event`].
+    pub fn add_event(&self, mod_id: ModId, event: Value) -> EventId {
+        let mut state = self.lock();
+        let id = state.id(&event);
+        let mut event = merge(fixtures::event(mod_id.get(), id), event);
+        event["mod_id"] = json!(mod_id);
+        state.events.push(validate::<Event>("event", event));
+        Id::new(id)
+    }
+
+    /// Add a user and return its id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the merged object is not a valid [`User`].
+    pub fn add_user(&self, user: Value) -> UserId {
+        let mut state = self.lock();
+        let id = state.id(&user);
+        let user = validate::<User>("user", merge(fixtures::user(id), user));
+        state.users.push(user);
+        Id::new(id)
+    }
+
+    /// Issue an access token for a user.
+    pub fn issue_token(&self, user_id: UserId) -> String {
+        self.lock().issue_token(user_id.get())
+    }
+
+    /// Returns the last security code sent to the email address.
+    pub fn security_code(&self, email: &str) -> Option<String> {
+        self.lock().security_codes.get(email).cloned()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().expect("mock server state poisoned")
+    }
+
+    fn handle(&self, request: &http::request::Parts, body: &[u8]) -> (StatusCode, Value) {
+        let query: Vec<(String, String)> = request
+            .uri
+            .query()
+            .map(|q| {
+                url::form_urlencoded::parse(q.as_bytes())
+                    .into_owned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        let form: HashMap<String, String> =
+            url::form_urlencoded::parse(body).into_owned().collect();
+        let token = request
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+
+        if token.is_none() && !query.iter().any(|(k, _)| k == "api_key") {
+            return error(StatusCode::UNAUTHORIZED, 11000, "API key is missing.");
+        }
+
+        let mut state = self.lock();
+        let user = match token {
+            Some(token) => match state.tokens.get(token) {
+                Some(user_id) => Some(*user_id),
+                None => return error(StatusCode::UNAUTHORIZED, 11005, "Invalid access token."),
+            },
+            None => None,
+        };
+
+        let path = request.uri.path();
+        let path = path.strip_prefix("/v1").unwrap_or(path);
+        let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+        state.route(&request.method, &segments, &query, &form, user)
+    }
+}
+
+impl Transport for MockServer {
+    fn execute(&self, request: Request) -> BoxFuture<'static, Result<Response, BoxError>> {
+        let server = self.clone();
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let body = body.bytes().await?;
+            let (status, value) = server.handle(&parts, &body);
+
+            let request_id = server.request_ids.fetch_add(1, Ordering::Relaxed) + 1;
+            let body = if status == StatusCode::NO_CONTENT {
+                Body::empty()
+            } else {
+                Body::from(serde_json::to_vec(&value)?)
+            };
+            Ok(http::Response::builder()
+                .status(status)
+                .header(CONTENT_TYPE, "application/json")
+                .header("x-modio-request-id", format!("mock-{request_id}"))
+                .body(body)?)
+        })
+    }
+}
+
+impl State {
+    /// Returns the `id` property of the object or the next generated id.
+    fn id(&mut self, value: &Value) -> u64 {
+        let id = value.get("id").and_then(Value::as_u64);
+        match id {
+            Some(id) => {
+                self.next_id = self.next_id.max(id);
+                id
+            }
+            None => {
+                self.next_id += 1;
+                self.next_id
+            }
+        }
+    }
+
+    fn issue_token(&mut self, user_id: u64) -> String {
+        let token = format!("mock-token-{}-{}", user_id, self.tokens.len() + 1);
+        self.tokens.insert(token.clone(), user_id);
+        token
+    }
+
+    fn find<'a>(items: &'a [Value], field: &'a str, id: u64) -> impl Iterator<Item = &'a Value> {
+        items
+            .iter()
+            .filter(move |v| v.get(field).and_then(Value::as_u64) == Some(id))
+    }
+
+    fn game(&self, game_id: u64) -> Option<&Value> {
+        Self::find(&self.games, "id", game_id).next()
+    }
+
+    fn mod_(&self, game_id: u64, mod_id: u64) -> Option<&Value> {
+        Self::find(&self.mods, "id", mod_id)
+            .find(|m| m.get("game_id").and_then(Value::as_u64) == Some(game_id))
+    }
+
+    fn route(
+        &mut self,
+        method: &Method,
+        segments: &[&str],
+        query: &[(String, String)],
+        form: &HashMap<String, String>,
+        user: Option<u64>,
+    ) -> (StatusCode, Value) {
+        let ids = segments
+            .iter()
+            .map(|s| s.parse::<u64>().ok())
+            .collect::<Vec<_>>();
+        let id = |pos: usize| ids.get(pos).copied().flatten();
+
+        match (method, segments) {
+            (&Method::GET, ["games"]) => ok(filter::apply(self.games.clone(), query)),
+            (&Method::GET, ["games", _]) => match id(1).and_then(|g| self.game(g)) {
+                Some(game) => ok(game.clone()),
+                None => not_found(14001, "The requested game could not be found."),
+            },
+            (&Method::GET, ["games", _, "tags"]) => match id(1).and_then(|g| self.game(g)) {
+                Some(game) => {
+                    let tags = game["tag_options"].as_array().cloned().unwrap_or_default();
+                    ok(filter::apply(tags, query))
+                }
+                None => not_found(14001, "The requested game could not be found."),
+            },
+            (&Method::GET, ["games", _, "mods"]) => match id(1).and_then(|g| self.game(g)) {
+                Some(_) => {
+                    let mods = Self::find(&self.mods, "game_id", id(1).unwrap_or_default());
+                    ok(filter::apply(mods.cloned().collect(), query))
+                }
+                None => not_found(14001, "The requested game could not be found."),
+            },
+            (&Method::GET, ["games", _, "mods", "events"]) => {
+                let game_id = id(1).unwrap_or_default();
+                let mods = Self::find(&self.mods, "game_id", game_id)
+                    .filter_map(|m| m["id"].as_u64())
+                    .collect::<BTreeSet<_>>();
+                let events = self
+                    .events
+                    .iter()
+                    .filter(|e| e["mod_id"].as_u64().map_or(false, |m| mods.contains(&m)))
+                    .cloned()
+                    .collect();
+                ok(filter::apply(events, query))
+            }
+            (_, ["games", _, "mods", _, ..]) => self.route_mod(method, segments, query, form, user),
+            (&Method::GET, ["authenticate", "terms"]) => ok(fixtures::terms()),
+            (&Method::POST, ["oauth", "emailrequest"]) => match form.get("email") {
+                Some(email) => {
+                    let code = format!("C{:04}", self.security_codes.len() + 1);
+                    self.security_codes.insert(email.clone(), code);
+                    let message =
+                        format!("Please check your email {email} for your security code.");
+                    ok(json!({"code": 200, "message": message}))
+                }
+                None => validation("The email field is required."),
+            },
+            (&Method::POST, ["oauth", "emailresponse" | "emailexchange"]) => {
+                let code = form.get("security_code");
+                let email = self
+                    .security_codes
+                    .iter()
+                    .find(|(_, c)| Some(*c) == code)
+                    .map(|(email, _)| email.clone());
+                match email {
+                    Some(email) => {
+                        self.security_codes.remove(&email);
+                        let user_id = match self.emails.get(&email) {
+                            Some(id) => *id,
+                            None => {
+                                let user_id = self.new_user();
+                                self.emails.insert(email, user_id);
+                                user_id
+                            }
+                        };
+                        ok(self.access_token(user_id))
+                    }
+                    None => error(
+                        StatusCode::UNAUTHORIZED,
+                        11012,
+                        "The security code is invalid or has expired.",
+                    ),
+                }
+            }
+            (&Method::POST, ["external", _]) => {
+                if form.get("terms_agreed").map(String::as_str) != Some("true") {
+                    return error(
+                        StatusCode::FORBIDDEN,
+                        11051,
+                        "The user has not agreed to the mod.io Terms of Use.",
+                    );
+                }
+                let user_id = self.new_user();
+                ok(self.access_token(user_id))
+            }
+            (&Method::POST, ["oauth", "logout"]) => {
+                self.tokens.retain(|_, u| Some(*u) != user);
+                ok(json!({"code": 200, "message": "You have successfully logged out of mod.io."}))
+            }
+            (&Method::GET, ["me"]) => match user {
+                Some(user) => match Self::find(&self.users, "id", user).next() {
+                    Some(user) => ok(user.clone()),
+                    None => not_found(21000, "The requested user could not be found."),
+                },
+                None => token_required(),
+            },
+            (&Method::GET, ["me", "events"]) => match user {
+                Some(user) => {
+                    let events = Self::find(&self.user_events, "user_id", user);
+                    ok(filter::apply(events.cloned().collect(), query))
+                }
+                None => token_required(),
+            },
+            (&Method::GET, ["me", "subscribed"]) => match user {
+                Some(user) => {
+                    let mods = self
+                        .mods
+                        .iter()
+                        .filter(|m| {
+                            let mod_id = m["id"].as_u64();
+                            mod_id.map_or(false, |m| self.subscriptions.contains(&(user, m)))
+                        })
+                        .cloned()
+                        .collect();
+                    ok(filter::apply(mods, query))
+                }
+                None => token_required(),
+            },
+            _ => not_found(14000, "The requested resource could not be found."),
+        }
+    }
+
+    fn route_mod(
+        &mut self,
+        method: &Method,
+        segments: &[&str],
+        query: &[(String, String)],
+        form: &HashMap<String, String>,
+        user: Option<u64>,
+    ) -> (StatusCode, Value) {
+        let game_id = segments[1].parse().unwrap_or_default();
+        let mod_id = segments[3].parse().unwrap_or_default();
+        let mod_ = match self.mod_(game_id, mod_id) {
+            Some(mod_) => mod_.clone(),
+            None => return not_found(15022, "The requested mod could not be found."),
+        };
+        let item = |items: &[Value], field: &str, code: u16, message: &str| {
+            let id = segments[5].parse().unwrap_or_default();
+            match Self::find(items, "id", id).find(|v| v[field].as_u64() == Some(mod_id)) {
+                Some(item) => ok(item.clone()),
+                None => not_found(code, message),
+            }
+        };
+
+        match (method, &segments[4..]) {
+            (&Method::GET, []) => ok(mod_),
+            (&Method::GET, ["files"]) => {
+                let files = Self::find(&self.files, "mod_id", mod_id).cloned().collect();
+                ok(filter::apply(files, query))
+            }
+            (&Method::GET, ["files", _]) => item(
+                &self.files,
+                "mod_id",
+                15010,
+                "The requested modfile could not be found.",
+            ),
+            (&Method::GET, ["comments"]) => {
+                let comments = Self::find(&self.comments, "resource_id", mod_id);
+                ok(filter::apply(comments.cloned().collect(), query))
+            }
+            (&Method::GET, ["comments", _]) => item(
+                &self.comments,
+                "resource_id",
+                15026,
+                "The requested comment could not be found.",
+            ),
+            (&Method::POST, ["comments"]) => {
+                let user = match user {
+                    Some(user) => user,
+                    None => return token_required(),
+                };
+                let content = match form.get("content") {
+                    Some(content) => content.clone(),
+                    None => return validation("The content field is required."),
+                };
+                let id = self.id(&Value::Null);
+                let reply_id = form
+                    .get("reply_id")
+                    .and_then(|id| id.parse::<u64>().ok())
+                    .unwrap_or(id);
+                let user = Self::find(&self.users, "id", user)
+                    .next()
+                    .cloned()
+                    .unwrap_or_else(|| fixtures::user(user));
+                let mut comment = fixtures::comment(mod_id, id);
+                comment["user"] = user;
+                comment["content"] = json!(content);
+                comment["reply_id"] = json!(reply_id);
+                comment["date_added"] = json!(now());
+                self.comments.push(comment.clone());
+                (StatusCode::CREATED, comment)
+            }
+            (&Method::GET, ["events"]) => {
+                let events = Self::find(&self.events, "mod_id", mod_id)
+                    .cloned()
+                    .collect();
+                ok(filter::apply(events, query))
+            }
+            (&Method::POST, ["subscribe"]) => match user {
+                Some(user) => {
+                    if self.subscriptions.insert((user, mod_id)) {
+                        self.add_user_event(game_id, mod_id, user, "USER_SUBSCRIBE");
+                    }
+                    (StatusCode::CREATED, mod_)
+                }
+                None => token_required(),
+            },
+            (&Method::DELETE, ["subscribe"]) => match user {
+                Some(user) => {
+                    if self.subscriptions.remove(&(user, mod_id)) {
+                        self.add_user_event(game_id, mod_id, user, "USER_UNSUBSCRIBE");
+                    }
+                    (StatusCode::NO_CONTENT, Value::Null)
+                }
+                None => token_required(),
+            },
+            _ => not_found(14000, "The requested resource could not be found."),
+        }
+    }
+
+    fn add_user_event(&mut self, game_id: u64, mod_id: u64, user_id: u64, event_type: &str) {
+        let id = self.id(&Value::Null);
+        let mut event = fixtures::user_event(game_id, mod_id, user_id, id);
+        event["date_added"] = json!(now());
+        event["event_type"] = json!(event_type);
+        self.user_events.push(event);
+    }
+
+    fn new_user(&mut self) -> u64 {
+        let id = self.id(&Value::Null);
+        self.users.push(fixtures::user(id));
+        id
+    }
+
+    fn access_token(&mut self, user_id: u64) -> Value {
+        json!({
+            "code": 200,
+            "access_token": self.issue_token(user_id),
+            "date_expires": now() + TOKEN_LIFETIME,
+        })
+    }
+}
+
+/// Merge the properties of `value` into the default object.
+fn merge(mut default: Value, value: Value) -> Value {
+    if let (Some(default), Value::Object(value)) = (default.as_object_mut(), value) {
+        default.extend(value);
+    }
+    default
+}
+
+fn validate<T: DeserializeOwned>(kind: &str, value: Value) -> Value {
+    if let Err(e) = serde_json::from_value::<T>(value.clone()) {
+        panic!("invalid {kind} object: {e}");
+    }
+    value
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+fn ok(value: Value) -> (StatusCode, Value) {
+    (StatusCode::OK, value)
+}
+
+fn error(status: StatusCode, error_ref: u16, message: &str) -> (StatusCode, Value) {
+    let error = json!({
+        "error": {
+            "code": status.as_u16(),
+            "error_ref": error_ref,
+            "message": message,
+        }
+    });
+    (status, error)
+}
+
+fn not_found(error_ref: u16, message: &str) -> (StatusCode, Value) {
+    error(StatusCode::NOT_FOUND, error_ref, message)
+}
+
+fn token_required() -> (StatusCode, Value) {
+    error(
+        StatusCode::UNAUTHORIZED,
+        11005,
+        "This endpoint requires an access token.",
+    )
+}
+
+fn validation(message: &str) -> (StatusCode, Value) {
+    error(StatusCode::UNPROCESSABLE_ENTITY, 13009, message)
+}
@@ -0,0 +1,247 @@
+//! Pluggable HTTP transport.
+//!
+//! All requests of a `Modio` client, including downloads and multipart uploads, are sent with a
+//! [`Transport`]. The default transport is `ReqwestTransport`, which uses the TLS backend
+//! selected with the `default-tls` or `rustls-tls` feature.
+//!
+//! A custom transport can be set with [`Builder::transport`] to send the requests with an
+//! existing HTTP stack. Without the `default-tls` and `rustls-tls` features the crate doesn't
+//! depend on `reqwest` and a custom transport is required.
+//!
+//! [`Builder::transport`]: crate::Builder::transport
+//!
+//! # Example
+//! ```no_run
+//! use futures_util::future::BoxFuture;
+//! use modio::transport::{BoxError, Request, Response, Transport};
+//! use modio::Modio;
+//!
+//! struct EngineTransport;
+//!
+//! impl Transport for EngineTransport {
+//!     fn execute(&self, request: Request) -> BoxFuture<'static, Result<Response, BoxError>> {
+//!         Box::pin(async move {
+//!             // Send the request with the HTTP stack of the engine.
+//!             # let _ = request;
+//!             # unimplemented!()
+//!         })
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let modio = Modio::builder("api-key")
+//!     .transport(EngineTransport)
+//!     .build()?;
+//! #    Ok(())
+//! # }
+//! ```
+use std::fmt;
+
+use bytes::{Bytes, BytesMut};
+use futures_util::future::BoxFuture;
+use futures_util::stream::{self, BoxStream};
+use futures_util::{Stream, StreamExt, TryFutureExt, TryStreamExt};
+use http::header::CONTENT_LENGTH;
+
+use crate::error::{self, Result};
+
+/// An HTTP request sent by a [`Transport`].
+pub type Request = http::Request<Body>;
+
+/// An HTTP response returned by a [`Transport`].
+pub type Response = http::Response<Body>;
+
+/// The error type of a [`Transport`].
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Sends HTTP requests.
+pub trait Transport: Send + Sync {
+    /// Send a request and return the response.
+    ///
+    /// Responses with a client or server error status must be returned as `Ok`.
+    fn execute(&self, request: Request) -> BoxFuture<'static, Result<Response, BoxError>>;
+}
+
+impl fmt::Debug for dyn Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transport")
+    }
+}
+
+/// The body of a request or response.
+pub struct Body {
+    inner: Inner,
+}
+
+enum Inner {
+    Bytes(Bytes),
+    Stream(BoxStream<'static, Result<Bytes, BoxError>>),
+}
+
+impl Body {
+    /// Create an empty body.
+    pub fn empty() -> Self {
+        Self::from(Bytes::new())
+    }
+
+    /// Create a body from a stream of bytes.
+    pub fn wrap_stream<S, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        Self {
+            inner: Inner::Stream(stream.map_err(Into::into).boxed()),
+        }
+    }
+
+    /// Returns the data of a body that is not a stream.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match &self.inner {
+            Inner::Bytes(bytes) => Some(bytes),
+            Inner::Stream(_) => None,
+        }
+    }
+
+    /// Convert the body into a stream of bytes.
+    pub fn into_stream(self) -> BoxStream<'static, Result<Bytes, BoxError>> {
+        match self.inner {
+            Inner::Bytes(bytes) if bytes.is_empty() => stream::empty().boxed(),
+            Inner::Bytes(bytes) => stream::once(async { Ok(bytes) }).boxed(),
+            Inner::Stream(stream) => stream,
+        }
+    }
+
+    /// Collect the full body.
+    pub async fn bytes(self) -> Result<Bytes, BoxError> {
+        match self.inner {
+            Inner::Bytes(bytes) => Ok(bytes),
+            Inner::Stream(stream) => {
+                let buf = stream
+                    .try_fold(BytesMut::new(), |mut buf, chunk| async move {
+                        buf.extend_from_slice(&chunk);
+                        Ok(buf)
+                    })
+                    .await?;
+                Ok(buf.freeze())
+            }
+        }
+    }
+}
+
+impl From<Bytes> for Body {
+    fn from(bytes: Bytes) -> Self {
+        Self {
+            inner: Inner::Bytes(bytes),
+        }
+    }
+}
+
+impl From<Vec<u8>> for Body {
+    fn from(data: Vec<u8>) -> Self {
+        Self::from(Bytes::from(data))
+    }
+}
+
+impl From<String> for Body {
+    fn from(data: String) -> Self {
+        Self::from(Bytes::from(data))
+    }
+}
+
+impl From<&'static str> for Body {
+    fn from(data: &'static str) -> Self {
+        Self::from(Bytes::from_static(data.as_bytes()))
+    }
+}
+
+impl fmt::Debug for Body {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            Inner::Bytes(bytes) => f.debug_tuple("Body").field(bytes).finish(),
+            Inner::Stream(_) => f.write_str("Body(Stream)"),
+        }
+    }
+}
+
+/// The default transport backed by a `reqwest` client.
+#[cfg(feature = "__tls")]
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "__tls")]
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "__tls")]
+impl Transport for ReqwestTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'static, Result<Response, BoxError>> {
+        let client = self.client.clone();
+        Box::pin(async move {
+            let request = reqwest::Request::try_from(request.map(reqwest_body))?;
+            let response = client.execute(request).await?;
+
+            let mut builder = http::Response::builder()
+                .status(response.status())
+                .version(response.version());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = response.headers().clone();
+            }
+            let body = Body::wrap_stream(response.bytes_stream());
+            Ok(builder.body(body)?)
+        })
+    }
+}
+
+#[cfg(feature = "__tls")]
+fn reqwest_body(body: Body) -> reqwest::Body {
+    match body.inner {
+        Inner::Bytes(bytes) => reqwest::Body::from(bytes),
+        Inner::Stream(stream) => reqwest::Body::wrap_stream(stream),
+    }
+}
+
+/// Returns a copy of a request, or `None` if the request has a streaming body.
+pub(crate) fn try_clone(req: &Request) -> Option<Request> {
+    let body = match &req.body().inner {
+        Inner::Bytes(bytes) => Body::from(bytes.clone()),
+        Inner::Stream(_) => return None,
+    };
+    let mut clone = http::Request::new(body);
+    *clone.method_mut() = req.method().clone();
+    *clone.uri_mut() = req.uri().clone();
+    *clone.version_mut() = req.version();
+    *clone.headers_mut() = req.headers().clone();
+    Some(clone)
+}
+
+/// Send a request with the transport of the client.
+///
+/// The default headers of the client are added to the request.
+pub(crate) async fn send(modio: &crate::Modio, mut req: Request) -> Result<Response> {
+    for (name, value) in &modio.inner.headers {
+        if !req.headers().contains_key(name) {
+            req.headers_mut().insert(name, value.clone());
+        }
+    }
+    modio
+        .inner
+        .transport
+        .execute(req)
+        .map_err(error::request)
+        .await
+}
+
+/// Returns the `Content-Length` header of a response.
+pub(crate) fn content_length(response: &Response) -> Option<u64> {
+    response
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+}
@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 /// See the [Access Token Object](https://docs.mod.io/#access-token-object) docs for more
 /// information.
-#[derive(Deserialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct AccessToken {
     #[serde(rename = "access_token")]
@@ -13,7 +13,7 @@ pub struct AccessToken {
 }
 
 /// See the [Terms Object](https://docs.mod.io/#terms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Terms {
     pub plaintext: String,
@@ -24,7 +24,7 @@ pub struct Terms {
 /// Part of [`Terms`]
 ///
 /// See the [Terms Object](https://docs.mod.io/#terms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Links {
     pub website: Link,
@@ -36,10 +36,47 @@ pub struct Links {
 /// Part of [`Terms`]
 ///
 /// See the [Terms Object](https://docs.mod.io/#terms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Link {
     pub text: String,
     pub url: Url,
     pub required: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{AccessToken, Terms};
+    use crate::types::tests::assert_roundtrip;
+
+    #[test]
+    fn access_token_roundtrip() {
+        assert_roundtrip::<AccessToken>(json!({
+            "access_token": "eyJ0eXAiOiXKV1QibCJhbLciOiJeiUzI1....",
+            "date_expires": 1570673249
+        }));
+    }
+
+    #[test]
+    fn terms_roundtrip() {
+        let link = |text: &str, url: &str, required: bool| {
+            json!({
+                "text": text,
+                "url": url,
+                "required": required,
+            })
+        };
+        assert_roundtrip::<Terms>(json!({
+            "plaintext": "This is a sample of terms text.",
+            "html": "<p>This is a sample of terms text.</p>",
+            "links": {
+                "website": link("Website", "https://mod.io/", false),
+                "terms": link("Terms of Use", "https://mod.io/terms", true),
+                "privacy": link("Privacy Policy", "https://mod.io/privacy", true),
+                "manage": link("Manage Account", "https://mod.io/me/account", false)
+            }
+        }));
+    }
+}
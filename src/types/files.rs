@@ -1,6 +1,7 @@
 use std::fmt;
 
 use serde::de::{Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
 use serde::Deserialize;
 use serde::Serialize;
 use url::Url;
@@ -10,7 +11,7 @@ use crate::types::{DeserializeField, MissingField, TargetPlatform};
 use super::id::{FileId, ModId};
 
 /// See the [Modfile Object](https://docs.mod.io/#modfile-object) docs for more information.
-#[derive(Clone, Serialize, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub struct File {
     pub id: FileId,
@@ -48,7 +49,8 @@ impl<'de> Deserialize<'de> for File {
             MetadataBlob,
             Download,
             Platforms,
-            Other(String),
+            #[serde(other)]
+            Other,
         }
 
         struct FileVisitor;
@@ -125,7 +127,7 @@ impl<'de> Deserialize<'de> for File {
                         Field::Platforms => {
                             platforms.deserialize_value("platforms", &mut map)?;
                         }
-                        Field::Other(_) => {
+                        Field::Other => {
                             map.next_value::<IgnoredAny>()?;
                         }
                     }
@@ -174,8 +176,30 @@ impl<'de> Deserialize<'de> for File {
     }
 }
 
+impl Serialize for File {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("File", 15)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("mod_id", &self.mod_id)?;
+        s.serialize_field("date_added", &self.date_added)?;
+        s.serialize_field("date_scanned", &self.virus_scan.date_scanned)?;
+        s.serialize_field("virus_status", &self.virus_scan.status)?;
+        s.serialize_field("virus_positive", &self.virus_scan.result)?;
+        s.serialize_field("filesize", &self.filesize)?;
+        s.serialize_field("filesize_uncompressed", &self.filesize_uncompressed)?;
+        s.serialize_field("filehash", &self.filehash)?;
+        s.serialize_field("filename", &self.filename)?;
+        s.serialize_field("version", &self.version)?;
+        s.serialize_field("changelog", &self.changelog)?;
+        s.serialize_field("metadata_blob", &self.metadata_blob)?;
+        s.serialize_field("download", &self.download)?;
+        s.serialize_field("platforms", &self.platforms)?;
+        s.end()
+    }
+}
+
 /// See the [Modfile Object](https://docs.mod.io/#modfile-object) docs for more information.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[non_exhaustive]
 pub struct VirusScan {
     pub date_scanned: u64,
@@ -203,14 +227,14 @@ newtype_enum! {
 }
 
 /// See the [Filehash Object](https://docs.mod.io/#filehash-object) docs for more information.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[non_exhaustive]
 pub struct FileHash {
     pub md5: String,
 }
 
 /// See the [Download Object](https://docs.mod.io/#download-object) docs for more information.
-#[derive(Deserialize, Serialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 #[non_exhaustive]
 pub struct Download {
     pub binary_url: Url,
@@ -228,7 +252,7 @@ impl fmt::Debug for Download {
 
 /// See the [Modfile Platform Object](https://docs.mod.io/#modfile-platform-object) docs for more
 /// information.
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[non_exhaustive]
 pub struct Platform {
     #[serde(rename = "platform")]
@@ -245,3 +269,71 @@ newtype_enum! {
         const DENIED   = 2;
     }
 }
+
+/// See the [Multipart Upload Object](https://docs.mod.io/#multipart-upload-object) docs for more
+/// information.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct UploadSession {
+    pub upload_id: String,
+    pub status: UploadStatus,
+}
+
+newtype_enum! {
+    /// See the [Multipart Upload Object](https://docs.mod.io/#multipart-upload-object) docs for
+    /// more information.
+    pub struct UploadStatus: u8 {
+        const INCOMPLETE = 0;
+        const PENDING    = 1;
+        const PROCESSING = 2;
+        const COMPLETED  = 3;
+        const CANCELLED  = 4;
+    }
+}
+
+/// See the [Multipart Upload Part Object](https://docs.mod.io/#multipart-upload-part-object) docs
+/// for more information.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct UploadPart {
+    pub upload_id: String,
+    pub part_number: u16,
+    pub part_size: u64,
+    pub date_added: u64,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use serde_json::json;
+
+    use super::File;
+    use crate::types::tests::assert_roundtrip;
+
+    pub(crate) fn file_json() -> serde_json::Value {
+        json!({
+            "id": 2,
+            "mod_id": 2,
+            "date_added": 1499841487,
+            "date_scanned": 1499841487,
+            "virus_status": 1,
+            "virus_positive": 0,
+            "filesize": 15181,
+            "filesize_uncompressed": 16384,
+            "filehash": {"md5": "2d4a0e2d7273db6b0a94b0740a88ad0d"},
+            "filename": "rogue-knight-v1.zip",
+            "version": "1.3",
+            "changelog": "VERSION 1.3 -- Changes -- Fixed critical castle floor bug.",
+            "metadata_blob": null,
+            "download": {
+                "binary_url": "https://api.mod.io/v1/games/1/mods/1/files/1/download/c489a035",
+                "date_expires": 1579316848
+            },
+            "platforms": [{"platform": "windows", "status": 1}]
+        })
+    }
+
+    #[test]
+    fn file_roundtrip() {
+        assert_roundtrip::<File>(file_json());
+    }
+}
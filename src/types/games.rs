@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fmt;
 
 use serde::de::{Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -10,7 +11,7 @@ use super::{deserialize_empty_object, DeserializeField, MissingField};
 use super::{Logo, Status, TargetPlatform};
 
 /// See the [Game Object](https://docs.mod.io/#game-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Game {
     pub id: GameId,
@@ -110,7 +111,7 @@ bitflags! {
 }
 
 /// See the [Icon Object](https://docs.mod.io/#icon-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Icon {
     pub filename: String,
@@ -134,7 +135,7 @@ impl fmt::Debug for Icon {
 
 /// See the [Header Image Object](https://docs.mod.io/#header-image-object) docs for more
 /// information.
-#[derive(Deserialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct HeaderImage {
     pub filename: String,
@@ -152,7 +153,7 @@ impl fmt::Debug for HeaderImage {
 
 /// See the [Game Statistics Object](https://docs.mod.io/#game-stats-object) docs for more
 /// information.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 #[non_exhaustive]
 pub struct Statistics {
     pub game_id: GameId,
@@ -174,7 +175,8 @@ impl<'de> Deserialize<'de> for Statistics {
             ModsDownloadsToday,
             ModsDownloadsDailyAverage,
             DateExpires,
-            Other(String),
+            #[serde(other)]
+            Other,
         }
 
         struct StatisticsVisitor;
@@ -220,7 +222,7 @@ impl<'de> Deserialize<'de> for Statistics {
                         Field::DateExpires => {
                             expired_at.deserialize_value("date_expires", &mut map)?;
                         }
-                        Field::Other(_) => {
+                        Field::Other => {
                             map.next_value::<IgnoredAny>()?;
                         }
                     }
@@ -254,8 +256,25 @@ impl<'de> Deserialize<'de> for Statistics {
     }
 }
 
+impl Serialize for Statistics {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Statistics", 7)?;
+        s.serialize_field("game_id", &self.game_id)?;
+        s.serialize_field("mods_count_total", &self.mods_total)?;
+        s.serialize_field("mods_subscribers_total", &self.subscribers_total)?;
+        s.serialize_field("mods_downloads_total", &self.downloads.total)?;
+        s.serialize_field("mods_downloads_today", &self.downloads.today)?;
+        s.serialize_field(
+            "mods_downloads_daily_average",
+            &self.downloads.daily_average,
+        )?;
+        s.serialize_field("date_expires", &self.expired_at)?;
+        s.end()
+    }
+}
+
 /// Part of [`Statistics`]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 #[non_exhaustive]
 pub struct Downloads {
     pub total: u32,
@@ -265,7 +284,7 @@ pub struct Downloads {
 
 /// See the [Game Tag Option Object](https://docs.mod.io/#game-tag-option-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct TagOption {
     pub name: String,
@@ -279,7 +298,7 @@ pub struct TagOption {
 }
 
 /// Defines the type of a tag. See [`TagOption`].
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum TagType {
@@ -297,7 +316,7 @@ impl fmt::Display for TagType {
 }
 
 /// See the [Theme Object](https://docs.mod.io/#theme-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct Theme {
     pub primary: String,
     pub dark: String,
@@ -308,7 +327,7 @@ pub struct Theme {
 }
 
 /// See the [Game OtherUrls Object](https://docs.mod.io/#game-otherurls-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 pub struct OtherUrl {
     pub label: String,
     pub url: Url,
@@ -324,7 +343,7 @@ impl fmt::Debug for OtherUrl {
 }
 
 /// See the [Game Platforms Object](https://docs.mod.io/#game-platforms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Platform {
     #[serde(rename = "platform")]
@@ -333,3 +352,88 @@ pub struct Platform {
     /// Indicates if users can upload files for this platform.
     pub locked: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Game;
+    use crate::types::tests::assert_roundtrip;
+
+    fn game_json() -> serde_json::Value {
+        let image = |size: &str| format!("https://thumb.modcdn.io/games/c4ca/1/{size}.png");
+        json!({
+            "id": 2,
+            "status": 1,
+            "date_added": 1493702614,
+            "date_updated": 1499410290,
+            "date_live": 1499841403,
+            "presentation_option": 0,
+            "submission_option": 1,
+            "curation_option": 0,
+            "community_options": 3,
+            "api_access_options": 3,
+            "maturity_options": 0,
+            "ugc_name": "mods",
+            "icon": {
+                "filename": "icon.png",
+                "original": image("icon"),
+                "thumb_64x64": image("64x64"),
+                "thumb_128x128": image("128x128"),
+                "thumb_256x256": image("256x256")
+            },
+            "logo": {
+                "filename": "logo.png",
+                "original": image("logo"),
+                "thumb_320x180": image("320x180"),
+                "thumb_640x360": image("640x360"),
+                "thumb_1280x720": image("1280x720")
+            },
+            "header": {"filename": "header.png", "original": image("header")},
+            "name": "Rogue Knight",
+            "name_id": "rogue-knight",
+            "summary": "Rogue Knight is a brand new 2D pixel platformer.",
+            "instructions": "Instructions on the process to upload mods.",
+            "instructions_url": "https://www.rogue-knight-game.com/modding/getting-started",
+            "profile_url": "https://rogue-knight.mod.io/",
+            "stats": {
+                "game_id": 2,
+                "mods_count_total": 13,
+                "mods_downloads_today": 204,
+                "mods_downloads_total": 27492,
+                "mods_downloads_daily_average": 1230,
+                "mods_subscribers_total": 16394,
+                "date_expires": 1492564103
+            },
+            "theme": {
+                "primary": "#44bfd5",
+                "dark": "#2c2c3f",
+                "light": "#ffffff",
+                "success": "#68D391",
+                "warning": "#d6af2e",
+                "danger": "#ff000e"
+            },
+            "other_urls": [{"label": "Our Steam Page", "url": "https://store.steampowered.com/"}],
+            "tag_options": [{
+                "name": "Theme",
+                "type": "checkboxes",
+                "tag_count_map": {"Horror": 52},
+                "hidden": false,
+                "locked": false,
+                "tags": ["Horror"]
+            }],
+            "platforms": [{"platform": "windows", "moderated": true, "locked": false}]
+        })
+    }
+
+    #[test]
+    fn game_roundtrip() {
+        assert_roundtrip::<Game>(game_json());
+
+        let mut game = game_json();
+        game["header"] = serde_json::Value::Null;
+        game["stats"] = serde_json::Value::Null;
+        game["theme"] = serde_json::Value::Null;
+        assert_roundtrip::<Game>(game);
+    }
+}
@@ -12,7 +12,7 @@ macro_rules! bitflags {
         $($t:tt)*
     ) => {
         $(#[$outer])*
-        #[derive(Copy, Clone, Eq, PartialEq, Deserialize)]
+        #[derive(Copy, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
         $vis struct $BitFlags($T);
 
         bitflags::bitflags! {
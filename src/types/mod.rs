@@ -3,6 +3,7 @@
 use std::fmt;
 
 use serde::de::Deserializer;
+use serde::ser::Serializer;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -21,7 +22,7 @@ use utils::{DeserializeField, MissingField};
 use self::id::{EventId, GameId, ModId, UserId};
 
 /// See the [Message Object](https://docs.mod.io/#message-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Message {
     pub code: u16,
@@ -59,7 +60,7 @@ where
 
 /// See the [Multiple Item Response](https://docs.mod.io/#response-formats) docs for more
 /// information.
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct List<T> {
     pub data: Vec<T>,
@@ -74,20 +75,25 @@ pub struct List<T> {
 }
 
 /// See the [Error Object](https://docs.mod.io/#error-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct ErrorResponse {
     pub error: Error,
 }
 
 /// See the [Error Object](https://docs.mod.io/#error-object) docs for more information.
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Error {
     pub code: u16,
     pub error_ref: u16,
     pub message: String,
-    #[serde(default, deserialize_with = "deserialize_errors")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_errors",
+        serialize_with = "serialize_errors",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub errors: Vec<(String, String)>,
 }
 
@@ -116,8 +122,15 @@ fn deserialize_errors<'de, D: Deserializer<'de>>(
     deserializer.deserialize_map(MapVisitor)
 }
 
+fn serialize_errors<S: Serializer>(
+    errors: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(errors.iter().map(|(k, v)| (k, v)))
+}
+
 /// See the [User Object](https://docs.mod.io/#user-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct User {
     pub id: UserId,
@@ -143,7 +156,7 @@ impl fmt::Debug for User {
 }
 
 /// See the [Avatar Object](https://docs.mod.io/#avatar-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Avatar {
     pub filename: String,
@@ -163,7 +176,7 @@ impl fmt::Debug for Avatar {
 }
 
 /// See the [Logo Object](https://docs.mod.io/#logo-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Logo {
     pub filename: String,
@@ -253,7 +266,7 @@ impl fmt::Display for TargetPlatform {
 }
 
 /// See the [User Event Object](https://docs.mod.io/#user-event-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Event {
     pub id: EventId,
@@ -266,7 +279,7 @@ pub struct Event {
 
 newtype_enum! {
     /// Type of user event that was triggered.
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     #[serde(transparent)]
     pub struct EventType<24> {
         /// User has joined a team.
@@ -324,10 +337,86 @@ where
 
 #[cfg(test)]
 mod tests {
-    use serde::Deserialize;
+    use serde::de::DeserializeOwned;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
     use serde_test::{assert_de_tokens, assert_tokens, Token};
 
     use super::{deserialize_empty_object, Error, EventType, TargetPlatform};
+    use super::{ErrorResponse, Event, List, User};
+
+    /// Asserts that the JSON deserializes to a value which serializes back to the same JSON and
+    /// deserializes to an equal value again.
+    pub(crate) fn assert_roundtrip<T>(json: serde_json::Value)
+    where
+        T: DeserializeOwned + Serialize + PartialEq,
+    {
+        let value: T = serde_json::from_value(json.clone()).unwrap();
+        let serialized = serde_json::to_value(&value).unwrap();
+        assert_eq!(serialized, json);
+        let deserialized: T = serde_json::from_value(serialized).unwrap();
+        assert!(deserialized == value);
+    }
+
+    pub(crate) fn user_json() -> serde_json::Value {
+        json!({
+            "id": 1,
+            "name_id": "xant",
+            "username": "XanT",
+            "date_online": 1509922961,
+            "avatar": {
+                "filename": "avatar.png",
+                "original": "https://thumb.modcdn.io/members/c4ca/1/profile/avatar.png",
+                "thumb_50x50": "https://thumb.modcdn.io/members/c4ca/1/profile/50x50.png",
+                "thumb_100x100": "https://thumb.modcdn.io/members/c4ca/1/profile/100x100.png"
+            },
+            "profile_url": "https://mod.io/u/xant"
+        })
+    }
+
+    #[test]
+    fn user_roundtrip() {
+        assert_roundtrip::<User>(user_json());
+
+        let mut user = user_json();
+        user["avatar"] = serde_json::Value::Null;
+        assert_roundtrip::<User>(user);
+    }
+
+    #[test]
+    fn error_roundtrip() {
+        assert_roundtrip::<ErrorResponse>(json!({
+            "error": {
+                "code": 422,
+                "error_ref": 13009,
+                "message": "Validation Failed. Please see below to fix invalid input.",
+                "errors": {"summary": "The mod summary cannot be more than 200 characters."}
+            }
+        }));
+        assert_roundtrip::<Error>(json!({
+            "code": 404,
+            "error_ref": 14000,
+            "message": "The requested resource could not be found."
+        }));
+    }
+
+    #[test]
+    fn list_roundtrip() {
+        assert_roundtrip::<List<Event>>(json!({
+            "data": [{
+                "id": 13,
+                "game_id": 7,
+                "mod_id": 13,
+                "user_id": 13,
+                "date_added": 1499846132,
+                "event_type": "USER_SUBSCRIBE"
+            }],
+            "result_count": 1,
+            "result_offset": 0,
+            "result_limit": 100,
+            "result_total": 1
+        }));
+    }
 
     #[test]
     fn deserialize_error_no_errors_field() {
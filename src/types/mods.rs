@@ -2,7 +2,8 @@ use std::collections::HashMap;
 use std::fmt;
 
 use serde::de::{Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
-use serde::Deserialize;
+use serde::ser::{SerializeSeq, SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 use super::files::File;
@@ -11,7 +12,7 @@ use super::{deserialize_empty_object, DeserializeField, MissingField, TargetPlat
 use super::{Logo, Status, User};
 
 /// See the [Mod Object](https://docs.mod.io/#mod-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Mod {
     pub id: ModId,
@@ -74,7 +75,7 @@ bitflags! {
 }
 
 /// See the [Mod Event Object](https://docs.mod.io/#mod-event-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Event {
     pub id: EventId,
@@ -86,7 +87,7 @@ pub struct Event {
 
 newtype_enum! {
     /// Type of mod event that was triggered.
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     #[serde(transparent)]
     pub struct EventType<24> {
         /// Primary file changed, the mod should be updated.
@@ -116,7 +117,7 @@ impl fmt::Display for EventType {
 
 /// See the [Mod Dependency Object](https://docs.mod.io/#mod-dependencies-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Dependency {
     pub mod_id: ModId,
@@ -125,7 +126,7 @@ pub struct Dependency {
 
 /// See the [Mod Media Object](https://docs.mod.io/#mod-media-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Media {
     #[serde(default = "Vec::new")]
@@ -137,7 +138,7 @@ pub struct Media {
 }
 
 /// See the [Image Object](https://docs.mod.io/#image-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Image {
     pub filename: String,
@@ -157,7 +158,7 @@ impl fmt::Debug for Image {
 
 /// See the [Statistics Object](https://docs.mod.io/#mod-stats-object) docs for more
 /// information.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 #[non_exhaustive]
 pub struct Statistics {
     pub mod_id: ModId,
@@ -187,7 +188,8 @@ impl<'de> Deserialize<'de> for Statistics {
             RatingsWeightedAggregate,
             RatingsDisplayText,
             DateExpires,
-            Other(String),
+            #[serde(other)]
+            Other,
         }
 
         struct StatisticsVisitor;
@@ -259,7 +261,7 @@ impl<'de> Deserialize<'de> for Statistics {
                         Field::DateExpires => {
                             date_expires.deserialize_value("date_expires", &mut map)?;
                         }
-                        Field::Other(_) => {
+                        Field::Other => {
                             map.next_value::<IgnoredAny>()?;
                         }
                     }
@@ -308,8 +310,34 @@ impl<'de> Deserialize<'de> for Statistics {
     }
 }
 
+impl Serialize for Statistics {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Statistics", 13)?;
+        s.serialize_field("mod_id", &self.mod_id)?;
+        s.serialize_field("downloads_today", &self.downloads_today)?;
+        s.serialize_field("downloads_total", &self.downloads_total)?;
+        s.serialize_field("subscribers_total", &self.subscribers_total)?;
+        s.serialize_field("popularity_rank_position", &self.popularity.rank_position)?;
+        s.serialize_field("popularity_rank_total_mods", &self.popularity.rank_total)?;
+        s.serialize_field("ratings_total", &self.ratings.total)?;
+        s.serialize_field("ratings_positive", &self.ratings.positive)?;
+        s.serialize_field("ratings_negative", &self.ratings.negative)?;
+        s.serialize_field(
+            "ratings_percentage_positive",
+            &self.ratings.percentage_positive,
+        )?;
+        s.serialize_field(
+            "ratings_weighted_aggregate",
+            &self.ratings.weighted_aggregate,
+        )?;
+        s.serialize_field("ratings_display_text", &self.ratings.display_text)?;
+        s.serialize_field("date_expires", &self.date_expires)?;
+        s.end()
+    }
+}
+
 /// Part of [`Statistics`]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 #[non_exhaustive]
 pub struct Popularity {
     pub rank_position: u32,
@@ -317,7 +345,7 @@ pub struct Popularity {
 }
 
 /// Part of [`Statistics`]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 #[non_exhaustive]
 pub struct Ratings {
     pub total: u32,
@@ -329,7 +357,7 @@ pub struct Ratings {
 }
 
 /// See the [Rating Object](https://docs.mod.io/#rating-object) docs for more information.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 #[non_exhaustive]
 pub enum Rating {
     Positive {
@@ -388,8 +416,31 @@ impl<'de> Deserialize<'de> for Rating {
     }
 }
 
+impl Serialize for Rating {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (game_id, mod_id, rating, date_added) = match *self {
+            Self::Positive {
+                game_id,
+                mod_id,
+                date_added,
+            } => (game_id, mod_id, 1i8, date_added),
+            Self::Negative {
+                game_id,
+                mod_id,
+                date_added,
+            } => (game_id, mod_id, -1i8, date_added),
+        };
+        let mut s = serializer.serialize_struct("Rating", 4)?;
+        s.serialize_field("game_id", &game_id)?;
+        s.serialize_field("mod_id", &mod_id)?;
+        s.serialize_field("rating", &rating)?;
+        s.serialize_field("date_added", &date_added)?;
+        s.end()
+    }
+}
+
 /// See the [Mod Platforms Object](https://docs.mod.io/#mod-platforms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Platform {
     #[serde(rename = "platform")]
@@ -401,7 +452,7 @@ pub struct Platform {
 }
 
 /// See the [Mod Tag Object](https://docs.mod.io/#mod-tag-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Tag {
     pub name: String,
@@ -549,8 +600,28 @@ impl<'de> Deserialize<'de> for MetadataMap {
     }
 }
 
+/// Serialize a `MetadataMap` to a sequence of key-value objects.
+impl Serialize for MetadataMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Element<'a> {
+            metakey: &'a str,
+            metavalue: &'a str,
+        }
+
+        let len = self.0.values().map(Vec::len).sum();
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for (metakey, values) in &self.0 {
+            for metavalue in values {
+                seq.serialize_element(&Element { metakey, metavalue })?;
+            }
+        }
+        seq.end()
+    }
+}
+
 /// See the [Comment Object](https://docs.mod.io/#comment-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Comment {
     pub id: CommentId,
@@ -565,7 +636,7 @@ pub struct Comment {
 
 /// See the [Team Member Object](https://docs.mod.io/#team-member-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct TeamMember {
     pub id: MemberId,
@@ -586,11 +657,114 @@ newtype_enum! {
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
     use serde_test::{assert_de_tokens, Token};
 
-    use super::{EventType, MetadataMap};
+    use super::{Comment, EventType, MetadataMap, Mod, Rating};
+    use crate::types::files::tests::file_json;
+    use crate::types::tests::{assert_roundtrip, user_json};
     use crate::types::List;
 
+    fn mod_json() -> serde_json::Value {
+        let image = |size: &str| format!("https://thumb.modcdn.io/mods/c81e/2/{size}.png");
+        json!({
+            "id": 2,
+            "game_id": 2,
+            "status": 1,
+            "visible": 1,
+            "submitted_by": user_json(),
+            "date_added": 1492564103,
+            "date_updated": 1499841487,
+            "date_live": 1499841403,
+            "maturity_option": 0,
+            "community_options": 1,
+            "price": 1.5,
+            "tax": 0,
+            "logo": {
+                "filename": "logo.png",
+                "original": image("logo"),
+                "thumb_320x180": image("320x180"),
+                "thumb_640x360": image("640x360"),
+                "thumb_1280x720": image("1280x720")
+            },
+            "homepage_url": "https://www.rogue-hdpack.com/",
+            "name": "Rogue Knight HD Pack",
+            "name_id": "rogue-knight-hd-pack",
+            "summary": "It's time to bask in the glory of beautiful 4k textures!",
+            "description": "<p>Rogue HD Pack does exactly what you thi...",
+            "description_plaintext": "Rogue HD Pack does exactly what you thi...",
+            "metadata_blob": "rogue,hd,high-res,4k,hd textures",
+            "profile_url": "https://rogue-knight.mod.io/rogue-knight-hd-pack",
+            "modfile": file_json(),
+            "media": {
+                "youtube": ["https://www.youtube.com/watch?v=dQw4w9WgXcQ"],
+                "sketchfab": [],
+                "images": [{
+                    "filename": "screenshot.png",
+                    "original": image("screenshot"),
+                    "thumb_320x180": image("screenshot-320x180")
+                }]
+            },
+            "metadata_kvp": [
+                {"metakey": "pistol-dmg", "metavalue": "800"},
+                {"metakey": "pistol-dmg", "metavalue": "850"}
+            ],
+            "tags": [{"name": "Unity", "date_added": 1499841487}],
+            "dependencies": false,
+            "stats": {
+                "mod_id": 2,
+                "popularity_rank_position": 13,
+                "popularity_rank_total_mods": 204,
+                "downloads_today": 327,
+                "downloads_total": 27492,
+                "subscribers_total": 16394,
+                "ratings_total": 1230,
+                "ratings_positive": 1047,
+                "ratings_negative": 183,
+                "ratings_percentage_positive": 91,
+                "ratings_weighted_aggregate": 0.5,
+                "ratings_display_text": "Very Positive",
+                "date_expires": 1492564103
+            },
+            "platforms": [{"platform": "windows", "modfile_live": 2}]
+        })
+    }
+
+    #[test]
+    fn mod_roundtrip() {
+        assert_roundtrip::<Mod>(mod_json());
+
+        let mut mod_ = mod_json();
+        mod_["modfile"] = serde_json::Value::Null;
+        assert_roundtrip::<Mod>(mod_);
+    }
+
+    #[test]
+    fn rating_roundtrip() {
+        for rating in [1, -1] {
+            assert_roundtrip::<Rating>(json!({
+                "game_id": 2,
+                "mod_id": 2,
+                "rating": rating,
+                "date_added": 1492564103
+            }));
+        }
+    }
+
+    #[test]
+    fn comment_roundtrip() {
+        assert_roundtrip::<Comment>(json!({
+            "id": 2,
+            "resource_id": 2,
+            "user": user_json(),
+            "date_added": 1499841487,
+            "reply_id": 1,
+            "thread_position": "01",
+            "karma": 1,
+            "content": "This mod is kickass! Great work!"
+        }));
+    }
+
     #[test]
     fn metadata_from_result_list_serde() {
         #[derive(Debug, PartialEq, serde::Deserialize)]
@@ -1,4 +1,5 @@
 //! User interface
+use crate::events::EventStream;
 use crate::prelude::*;
 use crate::types::files::File;
 use crate::types::games::Game;
@@ -22,7 +23,7 @@ impl Me {
 
     /// Returns the current user if authenticated.
     pub async fn current(self) -> Result<Option<User>> {
-        if self.modio.inner.credentials.token.is_some() {
+        if self.modio.inner.session.credentials().token.is_some() {
             let user = self.modio.request(Route::UserAuthenticated).send().await?;
             Ok(Some(user))
         } else {
@@ -62,6 +63,13 @@ impl Me {
         Query::new(self.modio, Route::UserEvents, filter)
     }
 
+    /// Returns a long-lived stream of the events of the authenticated user. [required: token]
+    ///
+    /// See [`EventStream`] and [Filters and sorting](filters::events).
+    pub fn event_stream(self, filter: Filter) -> EventStream<Event> {
+        EventStream::new(self.modio, Route::UserEvents, filter)
+    }
+
     /// Returns a `Query` interface to retrieve the mods the authenticated user is subscribed to.
     /// [required: token]
     ///
@@ -0,0 +1,232 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::auth::store::{CredentialStore, FileStore};
+use modio::auth::{
+    AuthOptions, EpicOptions, GalaxyOptions, ItchioOptions, OpenIdOptions, PsnOptions,
+};
+use modio::auth::{EmailFlowState, Token};
+use modio::{Credentials, Modio, Result};
+
+const TOKEN: &str = r#"{"code":200,"access_token":"token","date_expires":1570673249}"#;
+const USER: &str = r#"{"id":1,"name_id":"xant","username":"XanT","date_online":0,"avatar":{},
+    "profile_url":"https://mod.io/u/xant"}"#;
+const TERMS: &str = r#"{"plaintext":"terms","html":"<p>terms</p>","links":{
+    "website":{"text":"Website","url":"https://mod.io","required":false},
+    "terms":{"text":"Terms of Use","url":"https://mod.io/terms","required":true},
+    "privacy":{"text":"Privacy Policy","url":"https://mod.io/privacy","required":true},
+    "manage":{"text":"Manage Account","url":"https://mod.io/me/account","required":false}}}"#;
+const TERMS_REQUIRED: &str = r#"{"error":{"code":403,"error_ref":11051,"message":"terms"}}"#;
+const UNAUTHORIZED: &str = r#"{"error":{"code":401,"error_ref":11005,"message":"unauthorized"}}"#;
+
+type Param = (&'static str, &'static str);
+
+async fn external<T: Into<AuthOptions>>(path: &'static str, param: Param, opts: T) -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("POST", path),
+            request::body(url_decoded(contains(param))),
+            request::body(url_decoded(contains(("terms_agreed", "true")))),
+        ])
+        .respond_with(status_code(200).body(TOKEN)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let credentials = modio.auth().external(opts).await?;
+
+    let token = credentials.token.expect("access token");
+    assert_eq!(token.value, "token");
+    assert_eq!(token.expired_at, Some(1570673249));
+    Ok(())
+}
+
+#[tokio::test]
+async fn external_auth_galaxy() -> Result<()> {
+    let opts = GalaxyOptions::new("ticket").terms_agreed(true);
+    external("/v1/external/galaxyauth", ("appdata", "ticket"), opts).await
+}
+
+#[tokio::test]
+async fn external_auth_itchio() -> Result<()> {
+    let opts = ItchioOptions::new("jwt").terms_agreed(true);
+    external("/v1/external/itchioauth", ("itchio_token", "jwt"), opts).await
+}
+
+#[tokio::test]
+async fn external_auth_epic() -> Result<()> {
+    let opts = EpicOptions::new("jwt").terms_agreed(true);
+    external("/v1/external/epicgamesauth", ("id_token", "jwt"), opts).await
+}
+
+#[tokio::test]
+async fn external_auth_psn() -> Result<()> {
+    let opts = PsnOptions::new("code").env(256).terms_agreed(true);
+    external("/v1/external/psnauth", ("auth_code", "code"), opts).await?;
+
+    let opts = PsnOptions::new("code").env(8).terms_agreed(true);
+    external("/v1/external/psnauth", ("env", "8"), opts).await
+}
+
+#[tokio::test]
+async fn external_auth_openid() -> Result<()> {
+    let opts = OpenIdOptions::new("jwt").terms_agreed(true);
+    external("/v1/external/openidauth", ("id_token", "jwt"), opts).await
+}
+
+#[tokio::test]
+async fn email_flow_terms_required() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::method_path("GET", "/v1/authenticate/terms"))
+            .respond_with(status_code(200).body(TERMS)),
+    );
+    server.expect(
+        Expectation::matching(request::method_path("POST", "/v1/oauth/emailresponse"))
+            .respond_with(status_code(403).body(TERMS_REQUIRED)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let mut flow = modio.auth().email_flow();
+    flow.accept_terms();
+
+    let state = flow.security_code("QWERT").await?;
+    assert!(matches!(state, EmailFlowState::TermsRequired(t) if t.plaintext == "terms"));
+    assert!(!flow.terms_accepted());
+    Ok(())
+}
+
+fn expired(api_key: &str, token: &str) -> Credentials {
+    Credentials {
+        api_key: api_key.to_owned(),
+        token: Some(Token {
+            value: token.to_owned(),
+            expired_at: Some(1),
+        }),
+    }
+}
+
+/// Returns a client with the credentials that counts the invocations of the callback.
+fn client(server: &Server, credentials: Credentials) -> Result<(Modio, Arc<AtomicUsize>)> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let modio = Modio::builder(credentials)
+        .host(server.url_str("/v1"))
+        .on_token_expired(move |modio: Modio| {
+            let calls = Arc::clone(&counter);
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                assert!(modio.credentials().token.is_none());
+                Ok(Credentials::with_token("foobar", "fresh"))
+            }
+        })
+        .build()?;
+    Ok((modio, calls))
+}
+
+#[tokio::test]
+async fn reauthenticate_expired_token() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("GET", "/v1/me"),
+            request::headers(contains(("authorization", "Bearer fresh"))),
+        ])
+        .times(2)
+        .respond_with(status_code(200).body(USER)),
+    );
+
+    let (modio, calls) = client(&server, expired("foobar", "stale"))?;
+    let clone = modio.clone();
+
+    let (a, b) = tokio::join!(modio.user().current(), clone.user().current());
+    assert!(a?.is_some() && b?.is_some());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let token = clone.credentials().token.expect("access token");
+    assert_eq!(token.value, "fresh");
+    Ok(())
+}
+
+#[tokio::test]
+async fn reauthenticate_unauthorized_once() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("GET", "/v1/me"),
+            request::headers(contains(("authorization", "Bearer revoked"))),
+        ])
+        .times(2)
+        .respond_with(status_code(401).body(UNAUTHORIZED)),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("GET", "/v1/me"),
+            request::headers(contains(("authorization", "Bearer fresh"))),
+        ])
+        .times(2)
+        .respond_with(cycle![
+            status_code(200).body(USER),
+            status_code(401).body(UNAUTHORIZED),
+        ]),
+    );
+
+    let (modio, calls) = client(&server, ("foobar", "revoked").into())?;
+    assert!(modio.user().current().await?.is_some());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // The new token is rejected as well. The request is retried once per request.
+    let modio = modio.with_credentials(("foobar", "revoked"));
+    let err = modio.user().current().await.unwrap_err();
+    assert!(err.is_auth());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_and_load_credentials() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::method_path("POST", "/v1/oauth/emailresponse"))
+            .respond_with(status_code(200).body(TOKEN)),
+    );
+    server.expect(
+        Expectation::matching(request::method_path("POST", "/v1/oauth/logout"))
+            .respond_with(status_code(200).body(r#"{"code":200,"message":"ok"}"#)),
+    );
+    let dir = tempfile::tempdir().unwrap();
+    let store = Arc::new(FileStore::new(dir.path().join("credentials.json")));
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .load_credentials(Arc::clone(&store))
+        .await
+        .build()?;
+    assert!(modio.credentials().token.is_none());
+    let credentials = modio.auth().security_code("QWERT").await?;
+    assert!(store.load().await?.as_ref() == Some(&credentials));
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .load_credentials(Arc::clone(&store))
+        .await
+        .build()?;
+    let token = modio.credentials().token.expect("access token");
+    assert_eq!(token.value, "token");
+
+    // The saved token of another api key is ignored.
+    let other = Modio::builder("rotated")
+        .host(server.url_str("/v1"))
+        .load_credentials(Arc::clone(&store))
+        .await
+        .build()?;
+    assert_eq!(other.credentials().api_key, "rotated");
+    assert!(other.credentials().token.is_none());
+
+    modio.with_credentials(credentials).auth().logout().await?;
+    assert!(store.load().await?.is_none());
+    Ok(())
+}
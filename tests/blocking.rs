@@ -0,0 +1,153 @@
+#![cfg(feature = "blocking")]
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::blocking::Modio;
+use modio::filter::Filter;
+use modio::types::id::Id;
+use modio::Result;
+
+mod common;
+
+macro_rules! expect_requests {
+    ($server:expr, $(query:$query:expr, body:$body:expr),*) => {
+        $(
+            $server.expect(
+                Expectation::matching(all_of![
+                    request::method("GET"),
+                    request::path("/v1/games"),
+                    request::query(url_decoded($query)),
+                ])
+                .times(..)
+                .respond_with(status_code(200).body($body)),
+            );
+        )*
+    };
+}
+
+fn create_games_endpoint() -> Server {
+    let server = Server::run();
+
+    expect_requests!(
+        server,
+        query: not(contains(key("_offset"))),
+        body:  include_str!("fixtures/games-page1.json"),
+
+        query: contains(("_offset", "7")),
+        body:  include_str!("fixtures/games-page2.json"),
+
+        query: contains(("_offset", "14")),
+        body:  include_str!("fixtures/games-page3.json"),
+
+        query: contains(("_offset", "21")),
+        body:  include_str!("fixtures/games-page4.json"),
+
+        query: contains(("_offset", "28")),
+        body:  include_str!("fixtures/games-page5.json")
+    );
+
+    server
+}
+
+#[test]
+fn query() -> Result<()> {
+    let server = create_games_endpoint();
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+
+    let first = modio.games().search(Filter::default()).first()?;
+    assert_eq!(first.map(|g| g.id), Some(Id::new(2)));
+
+    let list = modio.games().search(Filter::default()).collect()?;
+    assert_eq!(32, list.len(), "result count");
+
+    let ids = modio
+        .games()
+        .search(Filter::default())
+        .iter()?
+        .map(|game| game.map(|g| g.id))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(ids, list.iter().map(|g| g.id).collect::<Vec<_>>());
+
+    let pages = modio.games().search(Filter::default()).paged()?.count();
+    assert_eq!(5, pages, "page count");
+    Ok(())
+}
+
+#[test]
+fn request_error() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/2")).respond_with(
+            status_code(404)
+                .body(r#"{"error":{"code":404,"error_ref":15022,"message":"not found"}}"#),
+        ),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let err = modio.mod_(Id::new(1), Id::new(2)).get().unwrap_err();
+
+    assert_eq!(err.error_ref(), Some(15022));
+    Ok(())
+}
+
+#[test]
+fn download() -> Result<()> {
+    const CONTENT: &[u8] = b"0123456789abcdefghij";
+
+    let server = Server::run();
+    let file = common::file_json(2, 3, CONTENT, &server.url_str("/download/mod.zip"));
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/2/files/3"))
+            .times(2)
+            .respond_with(status_code(200).body(file)),
+    );
+    server.expect(
+        Expectation::matching(request::path("/download/mod.zip"))
+            .times(2)
+            .respond_with(status_code(200).body(CONTENT)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let action = (Id::new(1), Id::new(2), Id::new(3));
+
+    let bytes = modio.download(action)?.verify().bytes()?;
+    assert_eq!(&bytes[..], CONTENT);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mod.zip");
+    modio.download(action)?.save_to_file(&path)?;
+    assert_eq!(std::fs::read(&path).unwrap(), CONTENT);
+    Ok(())
+}
+
+#[test]
+fn mod_tags_and_muted_users() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/2/tags")).respond_with(
+            status_code(200).body(common::list(&[
+                r#"{"name": "foo", "date_added": 0}"#.to_owned(),
+                r#"{"name": "bar", "date_added": 0}"#.to_owned(),
+            ])),
+        ),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/users/3/mute"),
+        ])
+        .respond_with(status_code(204)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let tags = modio
+        .mod_(Id::new(1), Id::new(2))
+        .tags()
+        .iter()?
+        .map(|tag| tag.map(|t| t.name))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(tags, ["foo", "bar"]);
+
+    modio.user().mute_user(Id::new(3))?;
+    Ok(())
+}
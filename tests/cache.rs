@@ -0,0 +1,216 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use httptest::{cycle, Expectation, Server};
+use httptest::{matchers::*, responders::*};
+
+use modio::cache::{Cache, MemoryStore};
+use modio::filter::prelude::{Eq, Name};
+use modio::filter::{custom_filter, Operator};
+use modio::games::{AddTagsOptions, TagType};
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+const TAGS: &str =
+    r#"{"data":[],"result_count":0,"result_offset":0,"result_limit":100,"result_total":0}"#;
+
+fn create_client(server: &Server, cache: Cache) -> Result<Modio> {
+    Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .cache(cache)
+        .build()
+}
+
+#[tokio::test]
+async fn fresh_response_is_cached() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/tags"),
+        ])
+        .times(1)
+        .respond_with(
+            status_code(200)
+                .insert_header("cache-control", "max-age=60")
+                .body(TAGS),
+        ),
+    );
+
+    let modio = create_client(&server, Cache::memory())?;
+    modio.game(Id::new(1)).tags().list().await?;
+    let tags = modio.game(Id::new(1)).tags().list().await?;
+
+    assert!(tags.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn stale_response_is_revalidated() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/tags"),
+            not(request::headers(contains(key("if-none-match")))),
+        ])
+        .times(1)
+        .respond_with(status_code(200).insert_header("etag", "\"v1\"").body(TAGS)),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/tags"),
+            request::headers(contains(("if-none-match", "\"v1\""))),
+        ])
+        .times(1)
+        .respond_with(status_code(304)),
+    );
+
+    let modio = create_client(&server, Cache::memory())?;
+    modio.game(Id::new(1)).tags().list().await?;
+    let tags = modio.game(Id::new(1)).tags().list().await?;
+
+    assert!(tags.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn date_expires_limits_freshness() -> Result<()> {
+    let server = Server::run();
+    let body = TAGS.replace('}', r#","date_expires":1}"#);
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/tags"))
+            .times(2)
+            .respond_with(cycle![
+                status_code(200).body(body.clone()),
+                status_code(200).body(body),
+            ]),
+    );
+
+    let cache = Cache::memory().default_ttl(Duration::from_secs(60));
+    let modio = create_client(&server, cache)?;
+    modio.game(Id::new(1)).tags().list().await?;
+    modio.game(Id::new(1)).tags().list().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn responses_are_cached_per_token() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/me/subscribed"))
+            .times(2)
+            .respond_with(
+                status_code(200)
+                    .insert_header("cache-control", "max-age=60")
+                    .body(TAGS),
+            ),
+    );
+
+    let modio = create_client(&server, Cache::memory())?;
+    let user1 = modio.with_credentials(("foobar", "token1"));
+    let user2 = modio.with_credentials(("foobar", "token2"));
+    user1
+        .user()
+        .subscriptions(Default::default())
+        .collect()
+        .await?;
+    user1
+        .user()
+        .subscriptions(Default::default())
+        .collect()
+        .await?;
+    user2
+        .user()
+        .subscriptions(Default::default())
+        .collect()
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn responses_are_cached_per_host_and_api_key() -> Result<()> {
+    let server1 = Server::run();
+    let server2 = Server::run();
+    for server in [&server1, &server2] {
+        server.expect(
+            Expectation::matching(request::path("/v1/games/1/tags"))
+                .times(2)
+                .respond_with(
+                    status_code(200)
+                        .insert_header("cache-control", "max-age=60")
+                        .body(TAGS),
+                ),
+        );
+    }
+
+    let store = Arc::new(MemoryStore::new());
+    for server in [&server1, &server2] {
+        let modio = create_client(server, Cache::new(Arc::clone(&store)))?;
+        modio.game(Id::new(1)).tags().list().await?;
+        modio.game(Id::new(1)).tags().list().await?;
+
+        let other_key = modio.with_credentials("other");
+        other_key.game(Id::new(1)).tags().list().await?;
+        other_key.game(Id::new(1)).tags().list().await?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn responses_are_cached_per_query() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games"))
+            .times(2)
+            .respond_with(
+                status_code(200)
+                    .insert_header("cache-control", "max-age=60")
+                    .body(TAGS),
+            ),
+    );
+
+    // Both filters decode to `name=a&z=c`.
+    let modio = create_client(&server, Cache::memory())?;
+    let filter = Name::eq("a&z=c");
+    modio.games().search(filter).collect().await?;
+    let filter = Name::eq("a").and(custom_filter("z", Operator::Equals, "c"));
+    modio.games().search(filter).collect().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn mutation_invalidates_cached_responses() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/tags"),
+        ])
+        .times(2)
+        .respond_with(
+            status_code(200)
+                .insert_header("cache-control", "max-age=60")
+                .body(TAGS),
+        ),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/games/1/tags"),
+        ])
+        .times(1)
+        .respond_with(status_code(201).body(r#"{"code":201,"message":"ok"}"#)),
+    );
+
+    let modio = create_client(&server, Cache::memory())?;
+    let modio = modio.with_credentials(("foobar", "token"));
+    modio.game(Id::new(1)).tags().list().await?;
+    modio.game(Id::new(1)).tags().list().await?;
+
+    let options = AddTagsOptions::new("Color", TagType::Checkboxes, &["Red".to_owned()]);
+    modio.game(Id::new(1)).tags().add(options).await?;
+    modio.game(Id::new(1)).tags().list().await?;
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! JSON responses shared by the integration tests.
+#![allow(dead_code)]
+
+/// A result list of the JSON objects.
+pub fn list(data: &[String]) -> String {
+    format!(
+        r#"{{"data": [{}], "result_count": {n}, "result_total": {n}, "result_limit": 100, "result_offset": 0}}"#,
+        data.join(","),
+        n = data.len(),
+    )
+}
+
+/// A mod of the game `1` with an optional modfile object.
+pub fn mod_json(mod_id: u64, modfile: Option<&str>) -> String {
+    include_str!("../fixtures/mod.json")
+        .replace("{mod_id}", &mod_id.to_string())
+        .replace("{modfile}", modfile.unwrap_or("null"))
+}
+
+/// A modfile whose size, hash and download url match the content. The version is the file id.
+pub fn file_json(mod_id: u64, file_id: u64, content: &[u8], url: &str) -> String {
+    format!(
+        r#"{{
+            "id": {file_id}, "mod_id": {mod_id}, "date_added": 0, "date_scanned": 0,
+            "virus_status": 1, "virus_positive": 0,
+            "filesize": {size}, "filesize_uncompressed": {size},
+            "filehash": {{"md5": "{md5:x}"}},
+            "filename": "mod.zip", "version": "{file_id}", "changelog": null, "metadata_blob": null,
+            "download": {{"binary_url": "{url}", "date_expires": 0}},
+            "platforms": []
+        }}"#,
+        size = content.len(),
+        md5 = md5::compute(content),
+    )
+}
+
+/// A user event of the game `1`. The event id is also used as `date_added`.
+pub fn user_event(id: u64, mod_id: u64, event_type: &str) -> String {
+    format!(
+        r#"{{"id": {id}, "game_id": 1, "mod_id": {mod_id}, "user_id": 1, "date_added": {id}, "event_type": "{event_type}"}}"#
+    )
+}
+
+/// A mod event. The event id is also used as `date_added`.
+pub fn mod_event(id: u64, mod_id: u64, event_type: &str) -> String {
+    format!(
+        r#"{{"id": {id}, "mod_id": {mod_id}, "user_id": 1, "date_added": {id}, "event_type": "{event_type}"}}"#
+    )
+}
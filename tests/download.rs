@@ -0,0 +1,209 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::types::id::Id;
+use modio::{DownloadAction, Modio, Result};
+
+mod common;
+
+const CONTENT: &[u8] = b"0123456789abcdefghij";
+
+fn file_json(server: &Server) -> String {
+    common::file_json(2, 3, CONTENT, &server.url_str("/download/mod.zip"))
+}
+
+fn create_server() -> Server {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/2/files/3"))
+            .respond_with(status_code(200).body(file_json(&server))),
+    );
+    server
+}
+
+fn action() -> DownloadAction {
+    (Id::new(1), Id::new(2), Id::new(3)).into()
+}
+
+#[tokio::test]
+async fn resume_partial_file() -> Result<()> {
+    let server = create_server();
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/download/mod.zip"),
+            not(request::headers(contains(key("range")))),
+        ])
+        .times(0)
+        .respond_with(status_code(200).body(CONTENT)),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/download/mod.zip"),
+            request::headers(contains(("range", "bytes=5-"))),
+        ])
+        .respond_with(
+            status_code(206)
+                .append_header("content-range", "bytes 5-19/20")
+                .body(&CONTENT[5..]),
+        ),
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mod.zip");
+    std::fs::write(&path, &CONTENT[..5]).unwrap();
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    modio
+        .download(action())
+        .await?
+        .resume_to_file(&path)
+        .await?;
+
+    assert_eq!(std::fs::read(&path).unwrap(), CONTENT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_ignored_range() -> Result<()> {
+    let server = create_server();
+    server.expect(
+        Expectation::matching(request::path("/download/mod.zip"))
+            .times(1)
+            .respond_with(status_code(200).body(CONTENT)),
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mod.zip");
+    std::fs::write(&path, b"garbage").unwrap();
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    modio
+        .download(action())
+        .await?
+        .resume_to_file(&path)
+        .await?;
+
+    assert_eq!(std::fs::read(&path).unwrap(), CONTENT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn verify_download() -> Result<()> {
+    let server = create_server();
+    server.expect(
+        Expectation::matching(request::path("/download/mod.zip"))
+            .respond_with(status_code(200).body(CONTENT)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let bytes = modio.download(action()).await?.verify().bytes().await?;
+
+    assert_eq!(bytes, CONTENT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn verify_checksum_mismatch() -> Result<()> {
+    let server = create_server();
+    server.expect(
+        Expectation::matching(request::path("/download/mod.zip"))
+            .respond_with(status_code(200).body("0123456789ABCDEFGHIJ")),
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mod.zip");
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let err = modio
+        .download(action())
+        .await?
+        .verify()
+        .save_to_file(&path)
+        .await
+        .unwrap_err();
+
+    assert!(err.is_download());
+    let source =
+        std::error::Error::source(&err).and_then(|e| e.downcast_ref::<modio::download::Error>());
+    assert!(matches!(
+        source,
+        Some(modio::download::Error::ChecksumMismatch { .. })
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn verify_size_mismatch() -> Result<()> {
+    let server = create_server();
+    server.expect(
+        Expectation::matching(request::path("/download/mod.zip"))
+            .respond_with(status_code(200).body(&CONTENT[..10])),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let err = modio
+        .download(action())
+        .await?
+        .verify()
+        .bytes()
+        .await
+        .unwrap_err();
+
+    let source =
+        std::error::Error::source(&err).and_then(|e| e.downcast_ref::<modio::download::Error>());
+    assert!(matches!(
+        source,
+        Some(modio::download::Error::SizeMismatch {
+            expected: 20,
+            actual: 10,
+            ..
+        })
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn progress_callback() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    let server = create_server();
+    server.expect(
+        Expectation::matching(request::path("/download/mod.zip"))
+            .respond_with(status_code(200).body(CONTENT)),
+    );
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let bytes = modio
+        .download(action())
+        .await?
+        .on_progress({
+            let reports = reports.clone();
+            move |p| reports.lock().unwrap().push((p.received(), p.total()))
+        })
+        .bytes()
+        .await?;
+
+    assert_eq!(bytes, CONTENT);
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.last(), Some(&(20, Some(20))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_status_error() -> Result<()> {
+    let server = create_server();
+    server.expect(
+        Expectation::matching(request::path("/download/mod.zip")).respond_with(
+            status_code(404)
+                .body(r#"{"error":{"code":404,"error_ref":15010,"message":"not found"}}"#),
+        ),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let err = modio.download(action()).await?.bytes().await.unwrap_err();
+
+    assert_eq!(err.status(), Some(http::StatusCode::NOT_FOUND));
+    assert_eq!(err.error_ref(), Some(15010));
+    Ok(())
+}
@@ -0,0 +1,238 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::{StreamExt, TryStreamExt};
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::events::dispatch::{CheckpointStore, Dispatcher, MemoryCheckpointStore};
+use modio::events::Cursor;
+use modio::filter::Filter;
+use modio::mods::{Event, EventType};
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+mod common;
+
+const INTERVAL: Duration = Duration::from_millis(10);
+
+fn events(ids: &[u64]) -> String {
+    let data = ids
+        .iter()
+        .map(|id| common::mod_event(*id, 2, "MODFILE_CHANGED"))
+        .collect::<Vec<_>>();
+    common::list(&data)
+}
+
+fn expect_events(server: &Server, after: &str, ids: &[u64]) {
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/games/1/mods/events"),
+            request::query(url_decoded(contains(("id-gt", after.to_owned())))),
+            request::query(url_decoded(contains(("_sort", "id")))),
+        ])
+        .times(..)
+        .respond_with(status_code(200).body(events(ids))),
+    );
+}
+
+#[tokio::test]
+async fn stream_resume_and_dedupe() -> Result<()> {
+    let server = Server::run();
+    expect_events(&server, "5", &[6, 7]);
+    // Overlapping responses are deduplicated.
+    expect_events(&server, "7", &[7, 8]);
+    expect_events(&server, "8", &[]);
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let st = modio
+        .game(Id::new(1))
+        .mods()
+        .event_stream(Filter::default())
+        .interval(INTERVAL)
+        .resume(Cursor::new(Id::new(5), 5))
+        .into_stream();
+
+    let events = st.take(3).try_collect::<Vec<_>>().await?;
+    let ids = events.iter().map(|e| e.id.get()).collect::<Vec<_>>();
+    assert_eq!(ids, [6, 7, 8]);
+    assert_eq!(Cursor::from_event(&events[2]), Cursor::new(Id::new(8), 8));
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_since_includes_same_second() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/games/1/mods/events"),
+            request::query(url_decoded(contains(("date_added-min", "10")))),
+        ])
+        .times(..)
+        .respond_with(status_code(200).body(events(&[10, 11]))),
+    );
+    expect_events(&server, "11", &[11, 12]);
+    expect_events(&server, "12", &[]);
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let st = modio
+        .game(Id::new(1))
+        .mods()
+        .event_stream(Filter::default())
+        .interval(INTERVAL)
+        .resume(Cursor::since(10))
+        .into_stream();
+
+    let events = st.take(3).try_collect::<Vec<_>>().await?;
+    let ids = events.iter().map(|e| e.id.get()).collect::<Vec<_>>();
+    assert_eq!(ids, [10, 11, 12]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_survives_transient_errors() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/events"))
+            .times(2)
+            .respond_with(cycle![
+                status_code(503)
+                    .body(r#"{"error": {"code": 503, "error_ref": 0, "message": "unavailable"}}"#),
+                status_code(200).body(events(&[6])),
+            ]),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let st = modio
+        .game(Id::new(1))
+        .mods()
+        .event_stream(Filter::default())
+        .interval(INTERVAL)
+        .resume(Cursor::new(Id::new(5), 5))
+        .into_stream();
+
+    let items = st.take(2).collect::<Vec<_>>().await;
+    assert!(items[0].as_ref().unwrap_err().is_response());
+    assert_eq!(items[1].as_ref().unwrap().id, Id::new(6));
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_ends_on_fatal_error() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/events")).respond_with(
+            status_code(401)
+                .body(r#"{"error": {"code": 401, "error_ref": 0, "message": "unauthorized"}}"#),
+        ),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let st = modio
+        .game(Id::new(1))
+        .mods()
+        .event_stream(Filter::default())
+        .interval(INTERVAL)
+        .into_stream();
+
+    let items = st.collect::<Vec<_>>().await;
+    assert_eq!(items.len(), 1);
+    assert!(items[0].as_ref().unwrap_err().is_auth());
+    Ok(())
+}
+
+#[tokio::test]
+async fn dispatcher_redelivers_failed_events() -> Result<()> {
+    let server = Server::run();
+    expect_events(&server, "5", &[6, 7]);
+    expect_events(&server, "6", &[7]);
+
+    let store = Arc::new(MemoryCheckpointStore::new());
+    store.save(Id::new(1), Cursor::new(Id::new(5), 5)).await?;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let failed = Arc::new(AtomicBool::new(false));
+    let deleted = Arc::new(AtomicUsize::new(0));
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let dispatcher = Dispatcher::new(modio)
+        .checkpoint_store(Arc::clone(&store))
+        .on(Id::new(1), EventType::MODFILE_CHANGED, {
+            let seen = Arc::clone(&seen);
+            let failed = Arc::clone(&failed);
+            move |event: Event| {
+                let seen = Arc::clone(&seen);
+                let failed = Arc::clone(&failed);
+                async move {
+                    seen.lock().unwrap().push(event.id.get());
+                    if event.id.get() == 7 && !failed.swap(true, Ordering::SeqCst) {
+                        return Err("handler failed");
+                    }
+                    Ok(())
+                }
+            }
+        })
+        .on(Id::new(1), EventType::MOD_DELETED, {
+            let deleted = Arc::clone(&deleted);
+            move |_: Event| {
+                deleted.fetch_add(1, Ordering::SeqCst);
+                async { Ok::<_, &str>(()) }
+            }
+        });
+
+    assert_eq!(dispatcher.poll().await?, 1);
+    assert_eq!(
+        store.load(Id::new(1)).await?,
+        Some(Cursor::new(Id::new(6), 6))
+    );
+
+    assert_eq!(dispatcher.poll().await?, 1);
+    assert_eq!(
+        store.load(Id::new(1)).await?,
+        Some(Cursor::new(Id::new(7), 7))
+    );
+
+    assert_eq!(*seen.lock().unwrap(), [6, 7, 7]);
+    assert_eq!(deleted.load(Ordering::SeqCst), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn dispatcher_skips_poison_event() -> Result<()> {
+    let server = Server::run();
+    expect_events(&server, "5", &[6, 7]);
+
+    let store = Arc::new(MemoryCheckpointStore::new());
+    store.save(Id::new(1), Cursor::new(Id::new(5), 5)).await?;
+
+    let dead = Arc::new(Mutex::new(Vec::new()));
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let dispatcher = Dispatcher::new(modio)
+        .checkpoint_store(Arc::clone(&store))
+        .max_attempts(2)
+        .on_dead_letter({
+            let dead = Arc::clone(&dead);
+            move |event, err| dead.lock().unwrap().push((event.id.get(), err.to_string()))
+        })
+        .on_any(Id::new(1), |event: Event| async move {
+            if event.id.get() == 6 {
+                return Err("poison");
+            }
+            Ok(())
+        });
+
+    assert_eq!(dispatcher.poll().await?, 0);
+    assert_eq!(
+        store.load(Id::new(1)).await?,
+        Some(Cursor::new(Id::new(5), 5))
+    );
+
+    assert_eq!(dispatcher.poll().await?, 1);
+    assert_eq!(
+        store.load(Id::new(1)).await?,
+        Some(Cursor::new(Id::new(7), 7))
+    );
+    assert_eq!(*dead.lock().unwrap(), [(6, "poison".to_owned())]);
+    Ok(())
+}
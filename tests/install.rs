@@ -0,0 +1,164 @@
+#![cfg(feature = "zip")]
+use std::io::{Cursor, Write};
+use std::path::Path;
+
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+use zip::write::{FileOptions, ZipWriter};
+
+use modio::install::Installer;
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+mod common;
+
+fn archive(content: &str) -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    zip.start_file("data/mod.txt", FileOptions::default())
+        .unwrap();
+    zip.write_all(content.as_bytes()).unwrap();
+    zip.finish().unwrap().into_inner()
+}
+
+fn mod_json(server: &Server, mod_id: u64, file_id: u64, archive: &[u8]) -> String {
+    let url = server.url_str(&format!("/download/{file_id}.zip"));
+    let modfile = common::file_json(mod_id, file_id, archive, &url);
+    common::mod_json(mod_id, Some(&modfile))
+}
+
+/// Serve the subscriptions with the `(mod_id, file_id, content)` of their primary modfiles.
+fn create_server(mods: &[(u64, u64, &str)]) -> Server {
+    let server = Server::run();
+    let mut data = Vec::new();
+    for (mod_id, file_id, content) in mods {
+        let archive = archive(content);
+        data.push(mod_json(&server, *mod_id, *file_id, &archive));
+        serve_archive(&server, *file_id, archive);
+    }
+    serve_subscriptions(&server, &data);
+    server
+}
+
+fn serve_archive(server: &Server, file_id: u64, archive: Vec<u8>) {
+    server.expect(
+        Expectation::matching(request::path(format!("/download/{file_id}.zip")))
+            .times(..)
+            .respond_with(status_code(200).body(archive)),
+    );
+}
+
+fn serve_subscriptions(server: &Server, data: &[String]) {
+    let body = common::list(data);
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/me/subscribed"),
+            request::query(url_decoded(contains(("game_id", "1")))),
+        ])
+        .times(1..)
+        .respond_with(status_code(200).body(body)),
+    );
+}
+
+fn read(dir: &Path, mod_id: u64) -> String {
+    std::fs::read_to_string(dir.join(format!("{mod_id}/data/mod.txt"))).unwrap()
+}
+
+#[tokio::test]
+async fn sync_subscriptions() -> Result<()> {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path();
+
+    let server = create_server(&[(2, 3, "foo"), (4, 5, "bar")]);
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let installer = Installer::new(modio, Id::new(1), dir);
+
+    let report = installer.sync().await?;
+    assert_eq!(report.installed, [Id::new(2), Id::new(4)]);
+    assert!(report.updated.is_empty());
+    assert_eq!(read(dir, 2), "foo");
+    assert_eq!(read(dir, 4), "bar");
+
+    let state = installer.state().await?;
+    let installed = state.get(Id::new(2)).unwrap();
+    assert_eq!(installed.file_id, Id::new(3));
+    assert_eq!(installed.version.as_deref(), Some("3"));
+
+    // Mod 2 has a new modfile and mod 4 is unsubscribed.
+    let server = create_server(&[(2, 6, "baz")]);
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let installer = Installer::new(modio, Id::new(1), dir);
+
+    let report = installer.sync().await?;
+    assert!(report.installed.is_empty());
+    assert_eq!(report.updated, [Id::new(2)]);
+    assert_eq!(report.removed, [Id::new(4)]);
+    assert_eq!(read(dir, 2), "baz");
+    assert!(!dir.join("4").exists());
+
+    let state = installer.state().await?;
+    assert_eq!(state.get(Id::new(2)).unwrap().file_id, Id::new(6));
+    assert!(state.get(Id::new(4)).is_none());
+
+    // Nothing changed.
+    let report = installer.sync().await?;
+    assert!(report.installed.is_empty() && report.updated.is_empty() && report.removed.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn sync_continues_after_failed_mod() -> Result<()> {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path();
+
+    // The modfile of mod 4 doesn't match the size and hash of its metadata.
+    let server = Server::run();
+    let (foo, bar) = (archive("foo"), archive("bar"));
+    let data = [
+        mod_json(&server, 2, 3, &foo),
+        mod_json(&server, 4, 5, &archive("corrupt")),
+    ];
+    serve_archive(&server, 3, foo);
+    serve_archive(&server, 5, bar);
+    serve_subscriptions(&server, &data);
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let installer = Installer::new(modio, Id::new(1), dir);
+
+    let report = installer.sync().await?;
+    assert_eq!(report.installed, [Id::new(2)]);
+    assert_eq!(report.failed.len(), 1);
+    let (mod_id, e) = &report.failed[0];
+    assert_eq!(*mod_id, Id::new(4));
+    assert!(e.is_download());
+
+    assert_eq!(read(dir, 2), "foo");
+    assert!(!dir.join("4").exists());
+    assert!(!dir.join(".4-5.zip").exists());
+    assert!(installer.state().await?.get(Id::new(4)).is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn install_invalid_archive() -> Result<()> {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path();
+
+    let server = Server::run();
+    let data = b"not a zip archive".to_vec();
+    serve_subscriptions(&server, &[mod_json(&server, 2, 3, &data)]);
+    serve_archive(&server, 3, data);
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let installer = Installer::new(modio, Id::new(1), dir);
+
+    let report = installer.sync().await?;
+    assert!(report.installed.is_empty());
+    let (mod_id, e) = &report.failed[0];
+    assert_eq!(*mod_id, Id::new(2));
+    assert!(e.is_archive());
+
+    assert!(!dir.join("2").exists());
+    assert!(!dir.join(".2-3").exists());
+    assert!(!dir.join(".2-3.zip").exists());
+    Ok(())
+}
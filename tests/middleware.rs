@@ -0,0 +1,73 @@
+#![cfg(feature = "tower")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::header::HeaderValue;
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+use tower::{layer::layer_fn, service_fn, ServiceBuilder, ServiceExt};
+
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+const TAGS: &str =
+    r#"{"data":[],"result_count":0,"result_offset":0,"result_limit":100,"result_total":0}"#;
+
+#[tokio::test]
+async fn layers_wrap_requests() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/games/1/tags"),
+            request::headers(contains(("x-trace-id", "abc"))),
+        ])
+        .times(2)
+        .respond_with(status_code(200).body(TAGS)),
+    );
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let counter = {
+        let count = Arc::clone(&count);
+        layer_fn(move |inner: modio::middleware::BoxService| {
+            let count = Arc::clone(&count);
+            service_fn(move |mut req: modio::transport::Request| {
+                count.fetch_add(1, Ordering::SeqCst);
+                req.headers_mut()
+                    .insert("x-trace-id", HeaderValue::from_static("abc"));
+                inner.clone().oneshot(req)
+            })
+        })
+    };
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .layer(counter)
+        .build()?;
+
+    modio.game(Id::new(1)).tags().list().await?;
+    modio.game(Id::new(1)).tags().list().await?;
+
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn timeout_layer() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/tags")).respond_with(delay_and_then(
+            Duration::from_millis(500),
+            status_code(200).body(TAGS),
+        )),
+    );
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .layer(ServiceBuilder::new().timeout(Duration::from_millis(50)))
+        .build()?;
+
+    let err = modio.game(Id::new(1)).tags().list().await.unwrap_err();
+    assert!(err.is_request());
+    Ok(())
+}
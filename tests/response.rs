@@ -0,0 +1,85 @@
+use futures_util::TryStreamExt;
+use http::StatusCode;
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+const ERROR_404: &str = r#"{"error":{"code":404,"error_ref":14001,"message":"not found"}}"#;
+
+#[tokio::test]
+async fn page_meta() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games")).respond_with(
+            status_code(200)
+                .insert_header("x-modio-request-id", "req-1")
+                .insert_header("x-ratelimit-limit", "60")
+                .insert_header("x-ratelimit-remaining", "42")
+                .body(include_str!("fixtures/games-page1.json")),
+        ),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let filter = Default::default();
+    let mut pages = modio.games().search(filter).paged().await?;
+    let page = pages.try_next().await?.expect("first page");
+
+    assert!(!page.is_empty());
+    let meta = page.meta().expect("response meta");
+    assert_eq!(meta.status(), StatusCode::OK);
+    assert_eq!(meta.request_id(), Some("req-1"));
+    assert_eq!(meta.ratelimit_limit(), Some(60));
+    assert_eq!(meta.ratelimit_remaining(), Some(42));
+    Ok(())
+}
+
+#[tokio::test]
+async fn error_meta() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/2")).respond_with(
+            status_code(404)
+                .insert_header("x-modio-request-id", "req-2")
+                .insert_header("x-ratelimit-remaining", "41")
+                .body(ERROR_404),
+        ),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let err = modio.mod_(Id::new(1), Id::new(2)).get().await.unwrap_err();
+
+    assert_eq!(err.request_id(), Some("req-2"));
+    assert_eq!(err.error_ref(), Some(14001));
+    let meta = err.response_meta().expect("response meta");
+    assert_eq!(meta.status(), StatusCode::NOT_FOUND);
+    assert_eq!(meta.ratelimit_remaining(), Some(41));
+    Ok(())
+}
+
+#[tokio::test]
+async fn first_page_with_meta() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods")).respond_with(
+            status_code(200)
+                .insert_header("x-modio-request-id", "req-3")
+                .insert_header("x-ratelimit-remaining", "40")
+                .body(r#"{"data":[],"result_count":0,"result_offset":0,"result_limit":100,"result_total":0}"#),
+        ),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let mods = modio.game(Id::new(1)).mods();
+    let page = mods
+        .search(Default::default())
+        .first_page_with_meta()
+        .await?;
+
+    assert!(page.is_empty());
+    assert_eq!(page.request_id(), Some("req-3"));
+    let meta = page.meta().expect("response meta");
+    assert_eq!(meta.ratelimit_remaining(), Some(40));
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use httptest::{cycle, Expectation, Server};
+use httptest::{matchers::*, responders::*};
+
+use modio::retry::RetryPolicy;
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+const ERROR_503: &str = r#"{"error":{"code":503,"error_ref":0,"message":"unavailable"}}"#;
+const ERROR_429: &str = r#"{"error":{"code":429,"error_ref":11008,"message":"ratelimited"}}"#;
+
+fn create_client(server: &Server, max_retries: u32) -> Result<Modio> {
+    let policy = RetryPolicy::new(max_retries).initial_backoff(Duration::from_millis(1));
+    Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .retry_policy(policy)
+        .build()
+}
+
+#[tokio::test]
+async fn retry_server_error() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/tags"),
+        ])
+        .times(3)
+        .respond_with(cycle![
+            status_code(503).body(ERROR_503),
+            status_code(503).body(ERROR_503),
+            status_code(200).body(r#"{"data":[],"result_count":0,"result_offset":0,"result_limit":100,"result_total":0}"#),
+        ]),
+    );
+
+    let modio = create_client(&server, 2)?;
+    let tags = modio.game(Id::new(1)).tags().list().await?;
+
+    assert!(tags.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_exhausted() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::method("GET"))
+            .times(2)
+            .respond_with(status_code(503).body(ERROR_503)),
+    );
+
+    let modio = create_client(&server, 1)?;
+    let err = modio.game(Id::new(1)).tags().list().await.unwrap_err();
+
+    assert_eq!(err.status().map(|s| s.as_u16()), Some(503));
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_retry_for_post() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::method("POST"))
+            .times(1)
+            .respond_with(status_code(503).body(ERROR_503)),
+    );
+
+    let modio = create_client(&server, 3)?;
+    let err = modio.auth().request_code("foo@bar").await.unwrap_err();
+
+    assert_eq!(err.status().map(|s| s.as_u16()), Some(503));
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_after_ratelimit() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::method("POST"))
+            .times(2)
+            .respond_with(cycle![
+                status_code(429)
+                    .append_header("retry-after", "0")
+                    .body(ERROR_429),
+                status_code(200).body(r#"{"code":200,"message":"ok"}"#),
+            ]),
+    );
+
+    let modio = create_client(&server, 1)?;
+    modio.auth().request_code("foo@bar").await?;
+
+    Ok(())
+}
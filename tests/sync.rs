@@ -0,0 +1,138 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::sync::{SubscriptionSync, SyncDiff, SyncState};
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+use common::{list, mod_event, user_event};
+
+mod common;
+
+fn expect_initial(server: &Server) {
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/me/events"),
+            request::query(url_decoded(contains(("_sort", "-id")))),
+        ])
+        .respond_with(status_code(200).body(list(&[user_event(10, 2, "USER_SUBSCRIBE")]))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/games/1/mods/events"),
+            request::query(url_decoded(contains(("_sort", "-id")))),
+        ])
+        .respond_with(status_code(200).body(list(&[mod_event(20, 2, "MOD_EDITED")]))),
+    );
+    server.expect(
+        Expectation::matching(request::path("/v1/me/subscribed"))
+            .respond_with(status_code(200).body(list(&[common::mod_json(2, None)]))),
+    );
+}
+
+#[tokio::test]
+async fn poll_events() -> Result<()> {
+    let server = Server::run();
+    expect_initial(&server);
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/me/events"),
+            request::query(url_decoded(contains(("id-gt", "10")))),
+            request::query(url_decoded(contains(("_sort", "id")))),
+        ])
+        .respond_with(status_code(200).body(list(&[
+            user_event(11, 4, "USER_SUBSCRIBE"),
+            user_event(12, 6, "USER_SUBSCRIBE"),
+            user_event(13, 6, "USER_UNSUBSCRIBE"),
+            user_event(14, 2, "USER_TEAM_JOIN"),
+        ]))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/games/1/mods/events"),
+            request::query(url_decoded(contains(("id-gt", "20")))),
+            request::query(url_decoded(contains(("event_type", "MODFILE_CHANGED")))),
+        ])
+        .respond_with(status_code(200).body(list(&[
+            mod_event(21, 2, "MODFILE_CHANGED"),
+            mod_event(22, 9, "MODFILE_CHANGED"),
+            mod_event(23, 4, "MODFILE_CHANGED"),
+            mod_event(24, 2, "MODFILE_CHANGED"),
+        ]))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let mut sync = SubscriptionSync::new(modio, Id::new(1), SyncState::default());
+
+    let diff = sync.poll().await?;
+    assert_eq!(diff.install, [Id::new(2)]);
+    assert_eq!(sync.state().user_events().event_id, Some(Id::new(10)));
+    assert_eq!(sync.state().mod_events().event_id, Some(Id::new(20)));
+
+    let diff = sync.poll().await?;
+    assert_eq!(diff.install, [Id::new(4)]);
+    assert_eq!(diff.update, [Id::new(2)]);
+    assert!(diff.remove.is_empty());
+
+    let state = sync.state();
+    assert_eq!(state.user_events().event_id, Some(Id::new(14)));
+    assert_eq!(state.user_events().date_added, 14);
+    assert_eq!(state.mod_events().event_id, Some(Id::new(24)));
+    assert_eq!(
+        state.subscriptions().iter().copied().collect::<Vec<_>>(),
+        [Id::new(2), Id::new(4)]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn poll_unsubscribe_and_persist() -> Result<()> {
+    let server = Server::run();
+    expect_initial(&server);
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/me/events"),
+            request::query(url_decoded(contains(("id-gt", "10")))),
+        ])
+        .respond_with(status_code(200).body(list(&[user_event(
+            11,
+            2,
+            "USER_UNSUBSCRIBE",
+        )]))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/games/1/mods/events"),
+            request::query(url_decoded(contains(("id-gt", "20")))),
+        ])
+        .times(2)
+        .respond_with(status_code(200).body(list(&[]))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/me/events"),
+            request::query(url_decoded(contains(("id-gt", "11")))),
+        ])
+        .respond_with(status_code(200).body(list(&[]))),
+    );
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("sync.json");
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let state = SyncState::load(&path).await?;
+    let mut sync = SubscriptionSync::new(modio.clone(), Id::new(1), state);
+    sync.poll().await?;
+
+    let diff = sync.poll().await?;
+    assert_eq!(diff.remove, [Id::new(2)]);
+    sync.state().save(&path).await?;
+    assert!(!path.with_extension("json.tmp").exists());
+
+    // Resume from the persisted state.
+    let state = SyncState::load(&path).await?;
+    assert!(state.subscriptions().is_empty());
+    let mut sync = SubscriptionSync::new(modio, Id::new(1), state);
+    assert_eq!(sync.poll().await?, SyncDiff::default());
+    Ok(())
+}
@@ -0,0 +1,303 @@
+#![cfg(feature = "testing")]
+use futures_util::TryStreamExt;
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+use serde_json::json;
+
+use modio::auth::{EmailFlowState, GalaxyOptions, ItchioOptions, SteamOptions};
+use modio::filter::prelude::{Eq, Filter, Like, Name, OrderBy};
+use modio::mods::filters::Tags;
+use modio::sync::{SubscriptionSync, SyncState};
+use modio::testing::{MockServer, API_KEY};
+use modio::types::id::Id;
+use modio::types::EventType;
+use modio::{Modio, Result};
+
+#[tokio::test]
+async fn filter_and_paginate_mods() -> Result<()> {
+    let server = MockServer::new();
+    let game_id = server.add_game(json!({"name": "Example"}));
+    for i in 1..=5 {
+        let tag = if i % 2 == 0 { "Maps" } else { "Skins" };
+        server.add_mod(
+            game_id,
+            json!({"name": format!("Mod {i}"), "tags": [{"name": tag, "date_added": 0}]}),
+        );
+    }
+
+    let modio = server.client()?;
+    let mods = modio.game(game_id).mods();
+
+    let list = mods.search(Tags::eq("Maps")).collect().await?;
+    let names = list.iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["Mod 2", "Mod 4"]);
+
+    let list = mods
+        .search(Name::like("mod*").order_by(Name::desc()))
+        .collect()
+        .await?;
+    assert_eq!(list.first().map(|m| m.name.as_str()), Some("Mod 5"));
+
+    let pages = mods
+        .search(Filter::default().limit(2))
+        .paged()
+        .await?
+        .try_fold(0, |n, _| async move { Ok(n + 1) })
+        .await?;
+    assert_eq!(pages, 3);
+
+    let err = modio
+        .game(game_id)
+        .mod_(Id::new(999))
+        .get()
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_ref(), Some(15022));
+    assert!(err.request_id().is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn files_comments_and_events() -> Result<()> {
+    let server = MockServer::new();
+    let game_id = server.add_game(json!({}));
+    let mod_id = server.add_mod(game_id, json!({}));
+    let file_id = server.add_file(mod_id, json!({"version": "1.0"}));
+    server.add_comment(mod_id, json!({"content": "first"}));
+    server.add_event(mod_id, json!({"event_type": "MODFILE_CHANGED"}));
+
+    let modio = server.client()?;
+    let mod_ = modio.game(game_id).mod_(mod_id);
+
+    let file = mod_.file(file_id).get().await?;
+    assert_eq!(file.version.as_deref(), Some("1.0"));
+
+    let comments = mod_.comments().search(Filter::default()).collect().await?;
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].content, "first");
+
+    let events = mod_.events(Filter::default()).collect().await?;
+    assert_eq!(events.len(), 1);
+
+    let events = modio
+        .game(game_id)
+        .mods()
+        .events(Filter::default())
+        .collect()
+        .await?;
+    assert_eq!(events.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn email_auth_and_subscriptions() -> Result<()> {
+    let server = MockServer::new();
+    let game_id = server.add_game(json!({}));
+    let mod_id = server.add_mod(game_id, json!({}));
+
+    let modio = server.client()?;
+    modio.auth().request_code("john@example.com").await?;
+    let code = server
+        .security_code("john@example.com")
+        .expect("security code");
+    let credentials = modio.auth().security_code(&code).await?;
+
+    let modio = modio.with_credentials(credentials);
+    let user = modio.user().current().await?.expect("authenticated user");
+
+    modio.game(game_id).mod_(mod_id).subscribe().await?;
+    let subs = modio
+        .user()
+        .subscriptions(Filter::default())
+        .collect()
+        .await?;
+    assert_eq!(subs.iter().map(|m| m.id).collect::<Vec<_>>(), [mod_id]);
+
+    let comment = modio
+        .game(game_id)
+        .mod_(mod_id)
+        .comments()
+        .add("hello", None)
+        .await?;
+    assert_eq!(comment.user.id, user.id);
+
+    modio.game(game_id).mod_(mod_id).unsubscribe().await?;
+    let subs = modio
+        .user()
+        .subscriptions(Filter::default())
+        .collect()
+        .await?;
+    assert!(subs.is_empty());
+
+    modio.auth().logout().await?;
+    assert!(modio.user().current().await.unwrap_err().is_auth());
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_events_and_subscription_sync() -> Result<()> {
+    let server = MockServer::new();
+    let game_id = server.add_game(json!({}));
+    let foo = server.add_mod(game_id, json!({}));
+    let bar = server.add_mod(game_id, json!({}));
+    let user_id = server.add_user(json!({}));
+    let token = server.issue_token(user_id);
+    let modio = server.builder((API_KEY, token.as_str())).build()?;
+
+    modio.game(game_id).mod_(foo).subscribe().await?;
+    let mut sync = SubscriptionSync::new(modio.clone(), game_id, SyncState::default());
+    assert_eq!(sync.poll().await?.install, [foo]);
+
+    modio.game(game_id).mod_(bar).subscribe().await?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    server.add_event(
+        foo,
+        json!({"event_type": "MODFILE_CHANGED", "date_added": now}),
+    );
+    let diff = sync.poll().await?;
+    assert_eq!(diff.install, [bar]);
+    assert_eq!(diff.update, [foo]);
+
+    modio.game(game_id).mod_(foo).unsubscribe().await?;
+    let diff = sync.poll().await?;
+    assert_eq!(diff.remove, [foo]);
+    assert!(diff.install.is_empty() && diff.update.is_empty());
+
+    let events = modio.user().events(Filter::default()).collect().await?;
+    let events = events
+        .iter()
+        .map(|e| (e.mod_id, e.event_type))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        [
+            (foo, EventType::USER_SUBSCRIBE),
+            (bar, EventType::USER_SUBSCRIBE),
+            (foo, EventType::USER_UNSUBSCRIBE),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn email_flow() -> Result<()> {
+    let server = MockServer::new();
+    let modio = server.client()?;
+    let mut flow = modio.auth().email_flow();
+
+    let state = flow.request_code("john@example.com").await?;
+    assert!(matches!(state, EmailFlowState::TermsRequired(t) if t.links.terms.required));
+    assert!(server.security_code("john@example.com").is_none());
+
+    flow.accept_terms();
+    let state = flow.request_code("john@example.com").await?;
+    assert!(matches!(state, EmailFlowState::CodeSent));
+    let code = server
+        .security_code("john@example.com")
+        .expect("security code");
+
+    let err = flow.security_code("1234").await.unwrap_err();
+    assert!(err.is_validation());
+
+    let state = flow.security_code(&format!(" {code}\n")).await?;
+    let credentials = match state {
+        EmailFlowState::Authenticated(credentials) => credentials,
+        state => panic!("unexpected state: {state:?}"),
+    };
+    let modio = modio.with_credentials(credentials);
+    assert!(modio.user().current().await?.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn external_auth_requires_terms() -> Result<()> {
+    let server = MockServer::new();
+    let modio = server.client()?;
+
+    let err = modio
+        .auth()
+        .external(SteamOptions::new("ticket"))
+        .await
+        .unwrap_err();
+    assert!(err.is_terms_acceptance_required());
+
+    let terms = modio.auth().terms().await?;
+    assert!(terms.links.terms.required);
+
+    let credentials = modio
+        .auth()
+        .external(SteamOptions::new("ticket").terms_agreed(true))
+        .await?;
+    assert!(credentials.token.is_some());
+
+    let credentials = modio
+        .auth()
+        .external(GalaxyOptions::new("ticket").terms_agreed(true))
+        .await?;
+    assert!(credentials.token.is_some());
+
+    let credentials = modio
+        .auth()
+        .external(ItchioOptions::new("token").terms_agreed(true))
+        .await?;
+    assert!(credentials.token.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn record_and_replay() -> Result<()> {
+    const TAGS: &str =
+        r#"{"data":[],"result_count":0,"result_offset":0,"result_limit":100,"result_total":0}"#;
+    const ERROR: &str = r#"{"error":{"code":404,"error_ref":15022,"message":"not found"}}"#;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cassette.json");
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/tags"))
+            .respond_with(status_code(200).body(TAGS)),
+    );
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/2"))
+            .respond_with(status_code(404).body(ERROR)),
+    );
+    let host = server.url_str("/v1");
+
+    let modio = Modio::builder(("secret-key", "secret-token"))
+        .host(&host)
+        .record(&path)
+        .build()?;
+    modio.game(Id::new(1)).tags().list().await?;
+    modio
+        .game(Id::new(1))
+        .mod_(Id::new(2))
+        .get()
+        .await
+        .unwrap_err();
+    drop(server);
+
+    let cassette = std::fs::read_to_string(&path).expect("cassette file");
+    assert!(cassette.contains("REDACTED"));
+    assert!(!cassette.contains("secret-key"));
+    assert!(!cassette.contains("secret-token"));
+
+    let modio = Modio::builder(("other-key", "other-token"))
+        .host(&host)
+        .replay(&path)
+        .build()?;
+    let tags = modio.game(Id::new(1)).tags().list().await?;
+    assert!(tags.is_empty());
+    let err = modio
+        .game(Id::new(1))
+        .mod_(Id::new(2))
+        .get()
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_ref(), Some(15022));
+    let err = modio.game(Id::new(2)).tags().list().await.unwrap_err();
+    assert!(err.is_request());
+    Ok(())
+}
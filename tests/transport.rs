@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex};
+
+use futures_util::future::BoxFuture;
+use http::header::USER_AGENT;
+
+use modio::mods::EditTagsOptions;
+use modio::transport::{Body, BoxError, Request, Response, Transport};
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+/// Uri, user agent and body of a request.
+type Recorded = (String, Option<String>, Vec<u8>);
+
+#[derive(Clone, Default)]
+struct FakeTransport {
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl Transport for FakeTransport {
+    fn execute(&self, request: Request) -> BoxFuture<'static, Result<Response, BoxError>> {
+        let requests = Arc::clone(&self.requests);
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let user_agent = parts
+                .headers
+                .get(USER_AGENT)
+                .and_then(|v| v.to_str().ok())
+                .map(ToOwned::to_owned);
+            let body = body.bytes().await?;
+            requests
+                .lock()
+                .unwrap()
+                .push((parts.uri.to_string(), user_agent, body.to_vec()));
+
+            let body = r#"{"data":[],"result_count":0,"result_offset":0,"result_limit":100,"result_total":0}"#;
+            Ok(http::Response::builder()
+                .status(200)
+                .body(Body::from(body))?)
+        })
+    }
+}
+
+#[tokio::test]
+async fn custom_transport() -> Result<()> {
+    let transport = FakeTransport::default();
+    let modio = Modio::builder(("foobar", "token"))
+        .host("http://modio.test/v1")
+        .user_agent("engine/1.0")
+        .transport(transport.clone())
+        .build()?;
+
+    let tags = modio.game(Id::new(1)).tags().list().await?;
+    assert!(tags.is_empty());
+
+    modio
+        .game(Id::new(1))
+        .mod_(Id::new(2))
+        .tags()
+        .add(EditTagsOptions::new(&["foo".to_owned()]))
+        .await
+        .ok();
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+
+    let (uri, user_agent, _) = &requests[0];
+    assert_eq!(uri, "http://modio.test/v1/games/1/tags?api_key=foobar");
+    assert_eq!(user_agent.as_deref(), Some("engine/1.0"));
+
+    let (uri, _, body) = &requests[1];
+    assert_eq!(
+        uri,
+        "http://modio.test/v1/games/1/mods/2/tags?api_key=foobar"
+    );
+    assert_eq!(body, b"tags%5B%5D=foo");
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+use tempfile::NamedTempFile;
+
+use modio::files::multipart::CreateUploadOptions;
+use modio::files::{AddFileOptions, Files, UploadStatus};
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+mod common;
+
+const CONTENT: &[u8] = b"0123456789abcdefghij";
+const MULTIPART: &str = "/v1/games/1/mods/2/files/multipart";
+
+fn session_json(status: u8) -> String {
+    format!(r#"{{"upload_id": "abc", "status": {status}}}"#)
+}
+
+fn part_json(part_number: u16) -> String {
+    format!(
+        r#"{{"upload_id": "abc", "part_number": {part_number}, "part_size": 20, "date_added": 0}}"#
+    )
+}
+
+fn parts_json(parts: &[u16]) -> String {
+    let data = parts.iter().map(|n| part_json(*n)).collect::<Vec<_>>();
+    common::list(&data)
+}
+
+fn file_json() -> String {
+    common::file_json(2, 3, CONTENT, "https://mod.io/mod.zip")
+}
+
+fn files(server: &Server) -> Result<Files> {
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    Ok(modio.game(Id::new(1)).mod_(Id::new(2)).files())
+}
+
+fn tempfile() -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(CONTENT).unwrap();
+    file
+}
+
+#[tokio::test]
+async fn add_file_progress() -> Result<()> {
+    let server = Server::run();
+    let file = tempfile();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("POST", "/v1/games/1/mods/2/files"),
+            request::body(matches("filedata")),
+        ])
+        .respond_with(status_code(201).body(file_json())),
+    );
+
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let progress = sent.clone();
+    // The callback is only required to be `Send`.
+    let calls = std::cell::Cell::new(0);
+    let options = AddFileOptions::with_file(file.path()).on_progress(move |p| {
+        calls.set(calls.get() + 1);
+        progress.lock().unwrap().push((p.sent(), p.total()));
+    });
+    files(&server)?.add(options).await?;
+
+    let sent = sent.lock().unwrap();
+    assert_eq!(sent.last(), Some(&(20, Some(20))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_file_compute_filehash() -> Result<()> {
+    let server = Server::run();
+    let file = tempfile();
+    let md5 = format!("{:x}", md5::compute(CONTENT));
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("POST", "/v1/games/1/mods/2/files"),
+            request::body(matches("name=\"filehash\"")),
+            request::body(matches(md5)),
+        ])
+        .respond_with(status_code(201).body(file_json())),
+    );
+
+    let options = AddFileOptions::with_file(file.path()).compute_filehash();
+    files(&server)?.add(options).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_file_with_upload_session() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("POST", "/v1/games/1/mods/2/files"),
+            request::body(matches("name=\"upload_id\"")),
+            request::body(matches("abc")),
+        ])
+        .respond_with(status_code(201).body(file_json())),
+    );
+
+    let options = AddFileOptions::with_upload_session("abc");
+    files(&server)?.add(options).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn multipart_upload_file() -> Result<()> {
+    let server = Server::run();
+    let file = tempfile();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("POST", format!("{MULTIPART}/create")),
+            request::body(url_decoded(contains(("filename", "mod.zip")))),
+        ])
+        .respond_with(status_code(201).body(session_json(0))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("GET", MULTIPART),
+            request::query(url_decoded(contains(("upload_id", "abc")))),
+        ])
+        .respond_with(status_code(200).body(parts_json(&[]))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("PUT", MULTIPART),
+            request::query(url_decoded(contains(("upload_id", "abc")))),
+            request::headers(contains(("content-range", "bytes 0-19/20"))),
+            request::body(CONTENT),
+        ])
+        .respond_with(status_code(201).body(part_json(1))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("POST", format!("{MULTIPART}/complete")),
+            request::query(url_decoded(contains(("upload_id", "abc")))),
+        ])
+        .respond_with(status_code(200).body(session_json(1))),
+    );
+
+    let sent = Arc::new(Mutex::new(0));
+    let progress = sent.clone();
+    let upload = files(&server)?
+        .create_multipart_upload(CreateUploadOptions::new("mod.zip"))
+        .await?
+        .on_progress(move |p| *progress.lock().unwrap() = p.sent());
+    assert_eq!(upload.upload_id(), "abc");
+
+    let session = upload.upload_file(file.path()).await?;
+    assert_eq!(session.status, UploadStatus::PENDING);
+    assert_eq!(*sent.lock().unwrap(), 20);
+    Ok(())
+}
+
+#[tokio::test]
+async fn multipart_upload_resume() -> Result<()> {
+    let server = Server::run();
+    let file = tempfile();
+    server.expect(
+        Expectation::matching(request::method_path("GET", MULTIPART))
+            .respond_with(status_code(200).body(parts_json(&[1]))),
+    );
+    server.expect(
+        Expectation::matching(request::method_path(
+            "POST",
+            format!("{MULTIPART}/complete"),
+        ))
+        .respond_with(status_code(200).body(session_json(1))),
+    );
+
+    // The only part is already uploaded and not reported.
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let upload = files(&server)?.multipart_upload("abc").on_progress({
+        let reports = reports.clone();
+        move |p| reports.lock().unwrap().push(p.sent())
+    });
+    upload.upload_file(file.path()).await?;
+    assert!(reports.lock().unwrap().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn multipart_upload_missing_file() -> Result<()> {
+    let server = Server::run();
+    let tmp = tempfile::tempdir().unwrap();
+
+    let upload = files(&server)?.multipart_upload("abc");
+    let err = upload.upload_file(tmp.path().join("missing.zip")).await;
+    assert!(err.unwrap_err().is_io());
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[tokio::test]
+async fn add_file_with_directory() -> Result<()> {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path().join("my-mod");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("mod.pak"), CONTENT).unwrap();
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method_path("POST", "/v1/games/1/mods/2/files"),
+            request::body(matches("name=\"filehash\"")),
+            request::body(matches("filename=\"my-mod.zip\"")),
+        ])
+        .respond_with(status_code(201).body(file_json())),
+    );
+
+    files(&server)?
+        .add(AddFileOptions::with_directory(&dir))
+        .await?;
+    Ok(())
+}